@@ -0,0 +1,163 @@
+use crate::fill::{is_closed, Polygon};
+use crate::{Edge, HersheyGlyph, PointF};
+
+fn cross(a: Edge, b: Edge) -> f64 {
+    a.x as f64 * b.y as f64 - b.x as f64 * a.y as f64
+}
+
+pub(crate) fn signed_area(ring: &[Edge]) -> f64 {
+    ring.windows(2)
+        .map(|pair| cross(pair[0], pair[1]))
+        .sum::<f64>()
+        / 2.0
+}
+
+fn weighted_centroid(ring: &[Edge]) -> (f64, f64, f64) {
+    let area = signed_area(ring);
+    let (x, y) = ring.windows(2).fold((0.0, 0.0), |(x, y), pair| {
+        let (a, b) = (pair[0], pair[1]);
+        let cross = cross(a, b);
+
+        (
+            x + (a.x as f64 + b.x as f64) * cross,
+            y + (a.y as f64 + b.y as f64) * cross,
+        )
+    });
+
+    (area, x / 6.0, y / 6.0)
+}
+
+pub fn path_area(path: &[Edge]) -> Option<f64> {
+    is_closed(path).then(|| signed_area(path).abs())
+}
+
+pub fn path_centroid(path: &[Edge]) -> Option<PointF> {
+    if !is_closed(path) {
+        return None;
+    }
+
+    let (area, x, y) = weighted_centroid(path);
+
+    (area != 0.0).then(|| PointF {
+        x: x / area,
+        y: y / area,
+    })
+}
+
+fn polygons_centroid(polygons: &[Polygon]) -> Option<PointF> {
+    let (mut area, mut x, mut y) = (0.0, 0.0, 0.0);
+
+    for polygon in polygons {
+        let outer = weighted_centroid(&polygon.outer);
+        let sign = outer.0.signum();
+
+        area += outer.0 * sign;
+        x += outer.1 * sign;
+        y += outer.2 * sign;
+
+        for hole in &polygon.holes {
+            let hole = weighted_centroid(hole);
+            let sign = hole.0.signum();
+
+            area -= hole.0 * sign;
+            x -= hole.1 * sign;
+            y -= hole.2 * sign;
+        }
+    }
+
+    (area != 0.0).then(|| PointF {
+        x: x / area,
+        y: y / area,
+    })
+}
+
+impl Polygon {
+    pub fn area(&self) -> f64 {
+        signed_area(&self.outer).abs()
+            - self
+                .holes
+                .iter()
+                .map(|hole| signed_area(hole).abs())
+                .sum::<f64>()
+    }
+
+    pub fn centroid(&self) -> Option<PointF> {
+        polygons_centroid(std::slice::from_ref(self))
+    }
+}
+
+impl HersheyGlyph {
+    /// Area enclosed by the glyph's closed paths. Open centerline strokes
+    /// enclose nothing, so a glyph drawn only with open strokes has area 0.
+    pub fn area(&self) -> f64 {
+        self.fill_polygons().iter().map(Polygon::area).sum()
+    }
+
+    /// Centroid of the enclosed area, or `None` when no closed path encloses
+    /// any area.
+    pub fn centroid(&self) -> Option<PointF> {
+        polygons_centroid(&self.fill_polygons())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HersheyFont;
+
+    fn glyph(data: &str) -> HersheyGlyph {
+        HersheyFont::new(data)
+            .unwrap()
+            .get_glyph(' ')
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn path_area_works() {
+        let square = &glyph("  714  6JZRRVRVVRVRR").paths[0];
+
+        assert_eq!(path_area(square), Some(16.0));
+        assert_eq!(path_area(&square[..3]), None);
+    }
+
+    #[test]
+    fn path_area_does_not_overflow() {
+        let big = 100_000;
+        let square = vec![
+            Edge { x: 0, y: 0 },
+            Edge { x: big, y: 0 },
+            Edge { x: big, y: big },
+            Edge { x: 0, y: big },
+            Edge { x: 0, y: 0 },
+        ];
+
+        assert_eq!(path_area(&square), Some(1e10));
+    }
+
+    #[test]
+    fn path_centroid_works() {
+        let square = &glyph("  714  6JZRRVRVVRVRR").paths[0];
+
+        assert_eq!(path_centroid(square), Some(PointF { x: 2.0, y: 2.0 }));
+    }
+
+    #[test]
+    fn glyph_area_subtracts_holes() {
+        let glyph = glyph("  714 12JZRRVRVVRVRR RSSSUUUUSSS");
+
+        assert_eq!(glyph.area(), 12.0);
+        assert_eq!(glyph.centroid(), Some(PointF { x: 2.0, y: 2.0 }));
+    }
+
+    #[test]
+    fn glyph_area_ignores_open_strokes() {
+        let open = glyph("  714  3JZRRVV");
+        let mixed = glyph("  714  9JZRRVRVVRVRR RXXZZ");
+
+        assert_eq!(open.area(), 0.0);
+        assert_eq!(open.centroid(), None);
+        assert_eq!(mixed.area(), 16.0);
+        assert_eq!(mixed.centroid(), Some(PointF { x: 2.0, y: 2.0 }));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::raster::{rasterize_paths, Bitmap, RasterOptions};
+use crate::sdf::{generate_sdf, SdfOptions};
+use crate::HersheyFont;
+
+const PADDING: usize = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AtlasRender {
+    Raster(RasterOptions),
+    Sdf(SdfOptions),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AtlasEntry {
+    pub character: char,
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+    pub uv: (f64, f64, f64, f64),
+    pub origin: (f64, f64),
+    pub advance: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphAtlas {
+    pub bitmap: Bitmap,
+    pub entries: Vec<AtlasEntry>,
+}
+
+impl GlyphAtlas {
+    pub fn entry(&self, character: char) -> Option<&AtlasEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.character == character)
+    }
+}
+
+impl HersheyFont {
+    pub fn build_atlas(
+        &self,
+        chars: impl IntoIterator<Item = char>,
+        render: &AtlasRender,
+        max_width: usize,
+    ) -> GlyphAtlas {
+        let scale = match render {
+            AtlasRender::Raster(options) => options.scale,
+            AtlasRender::Sdf(options) => options.scale,
+        };
+
+        let mut glyphs = chars
+            .into_iter()
+            .filter_map(|c| {
+                let glyph = self.get_glyph_checked(c)?;
+                let bitmap = match render {
+                    AtlasRender::Raster(options) => rasterize_paths(&glyph.paths, options),
+                    AtlasRender::Sdf(options) => generate_sdf(&glyph.paths, options),
+                };
+
+                Some((c, (glyph.right - glyph.left) as f64 * scale, bitmap))
+            })
+            .collect::<Vec<_>>();
+
+        glyphs.sort_by_key(|(_, _, bitmap)| std::cmp::Reverse(bitmap.height));
+
+        let mut placements = Vec::with_capacity(glyphs.len());
+        let (mut x, mut y, mut shelf_height, mut atlas_width) = (0, 0, 0, 0);
+
+        for (_, _, bitmap) in &glyphs {
+            if bitmap.pixels.is_empty() {
+                placements.push((0, 0));
+                continue;
+            }
+
+            if x > 0 && x + bitmap.width > max_width {
+                x = 0;
+                y += shelf_height + PADDING;
+                shelf_height = 0;
+            }
+
+            placements.push((x, y));
+            atlas_width = atlas_width.max(x + bitmap.width);
+            shelf_height = shelf_height.max(bitmap.height);
+            x += bitmap.width + PADDING;
+        }
+
+        let atlas_height = y + shelf_height;
+        let mut pixels = vec![0; atlas_width * atlas_height];
+        let mut entries = Vec::with_capacity(glyphs.len());
+
+        for ((character, advance, bitmap), (x, y)) in glyphs.into_iter().zip(placements) {
+            for row in 0..bitmap.height {
+                let source = &bitmap.pixels[row * bitmap.width..(row + 1) * bitmap.width];
+                let start = (y + row) * atlas_width + x;
+
+                pixels[start..start + bitmap.width].copy_from_slice(source);
+            }
+
+            let (u_scale, v_scale) = (
+                1.0 / atlas_width.max(1) as f64,
+                1.0 / atlas_height.max(1) as f64,
+            );
+
+            entries.push(AtlasEntry {
+                character,
+                x,
+                y,
+                width: bitmap.width,
+                height: bitmap.height,
+                uv: (
+                    x as f64 * u_scale,
+                    y as f64 * v_scale,
+                    (x + bitmap.width) as f64 * u_scale,
+                    (y + bitmap.height) as f64 * v_scale,
+                ),
+                origin: bitmap.origin,
+                advance,
+            });
+        }
+
+        GlyphAtlas {
+            bitmap: Bitmap {
+                width: atlas_width,
+                height: atlas_height,
+                origin: (0.0, 0.0),
+                pixels,
+            },
+            entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_atlas_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3G][BIb\n  720  3G][BIb").unwrap();
+        let atlas = font.build_atlas(
+            " !\"".chars(),
+            &AtlasRender::Raster(RasterOptions::default()),
+            64,
+        );
+
+        let first = atlas.entry('!').unwrap();
+        let second = atlas.entry('"').unwrap();
+
+        assert_eq!(atlas.entries.len(), 3);
+        assert_eq!(atlas.entry(' ').unwrap().width, 0);
+        assert_eq!(first.advance, 22.0);
+        assert_eq!((first.x, first.y), (0, 0));
+        assert_eq!((second.x, second.y), (first.width + PADDING, 0));
+        assert_eq!(atlas.bitmap.width, first.width * 2 + PADDING);
+    }
+
+    #[test]
+    fn build_atlas_wraps_shelves() {
+        let font = HersheyFont::new("  720  3G][BIb\n  720  3G][BIb").unwrap();
+        let atlas = font.build_atlas(" !".chars(), &AtlasRender::Sdf(SdfOptions::default()), 30);
+
+        let second = atlas.entry('!').unwrap();
+
+        assert_eq!(second.x, 0);
+        assert!(second.y > 0);
+        assert_eq!(second.uv.3, 1.0);
+    }
+}
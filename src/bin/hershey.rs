@@ -0,0 +1,218 @@
+use anyhow::{anyhow, bail, Context, Result};
+use hershey_parser::eps::EpsOptions;
+use hershey_parser::gcode::GcodeOptions;
+use hershey_parser::hpgl::HpglOptions;
+use hershey_parser::pdf::PdfOptions;
+use hershey_parser::svg::SvgOptions;
+use hershey_parser::HersheyFont;
+use std::fs;
+
+const USAGE: &str = "Usage:
+  hershey info <font.jhf>
+  hershey render <font.jhf> <text> [--svg|--eps|--pdf|--gcode|--hpgl <file>]
+  hershey convert <font.jhf> --to <jhf|json> [--output <file>]";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Info {
+        font: String,
+    },
+    Render {
+        font: String,
+        text: String,
+        format: String,
+        output: Option<String>,
+    },
+    Convert {
+        font: String,
+        to: String,
+        output: Option<String>,
+    },
+}
+
+fn parse_args(args: &[String]) -> Result<Command> {
+    let mut positional = Vec::new();
+    let mut format = None;
+    let mut output = None;
+    let mut to = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .cloned()
+                .ok_or_else(|| anyhow!("Missing value for {}", arg))
+        };
+
+        match arg.as_str() {
+            "--svg" | "--eps" | "--pdf" | "--gcode" | "--hpgl" => {
+                format = Some(arg[2..].to_string());
+                output = Some(value()?);
+            }
+            "--output" | "-o" => output = Some(value()?),
+            "--to" => to = Some(value()?),
+            flag if flag.starts_with("--") => bail!("Unknown option {}", flag),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let (Some(command), Some(font)) = (positional.next(), positional.next()) else {
+        bail!("{}", USAGE);
+    };
+
+    let command = match command.as_str() {
+        "info" => Command::Info { font },
+        "render" => Command::Render {
+            font,
+            text: positional
+                .next()
+                .ok_or_else(|| anyhow!("Missing text to render"))?,
+            format: format.unwrap_or_else(|| "svg".to_string()),
+            output,
+        },
+        "convert" => Command::Convert {
+            font,
+            to: to.ok_or_else(|| anyhow!("Missing --to format"))?,
+            output,
+        },
+        _ => bail!("Unknown command {}\n{}", command, USAGE),
+    };
+
+    if let Some(extra) = positional.next() {
+        bail!("Unexpected argument {}\n{}", extra, USAGE);
+    }
+
+    Ok(command)
+}
+
+fn load_font(path: &str) -> Result<HersheyFont> {
+    let data = fs::read_to_string(path).with_context(|| format!("Error reading {}", path))?;
+
+    HersheyFont::new(&data).with_context(|| format!("Error parsing {}", path))
+}
+
+fn write_output(output: Option<&str>, contents: &str) -> Result<()> {
+    match output {
+        Some(path) => fs::write(path, contents).with_context(|| format!("Error writing {}", path)),
+        None => {
+            print!("{}", contents);
+            Ok(())
+        }
+    }
+}
+
+fn info(font: &HersheyFont) -> String {
+    let stats = font.stats();
+    let mut lines = font.meta().map_or(Vec::new(), |meta| meta.lines());
+
+    lines.push(format!("Glyphs: {}", font.len()));
+    lines.push(format!(
+        "Bounds: left {}, top {}, right {}, bottom {}",
+        font.left, font.top, font.right, font.bottom
+    ));
+    lines.push(format!("Paths: {}", stats.path_count));
+    lines.push(format!("Vertices: {}", stats.vertex_count));
+    lines.extend(
+        font.diagnostics()
+            .iter()
+            .map(|diagnostic| format!("Warning: {}", diagnostic)),
+    );
+
+    lines.join("\n") + "\n"
+}
+
+fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Info { font } => write_output(None, &info(&load_font(&font)?)),
+        Command::Render {
+            font,
+            text,
+            format,
+            output,
+        } => {
+            let font = load_font(&font)?;
+            let contents = match format.as_str() {
+                "svg" => font.text_to_svg(&text, &SvgOptions::default()),
+                "eps" => font.text_to_eps(&text, &EpsOptions::default()),
+                "pdf" => font.text_to_pdf(&text, &PdfOptions::default()),
+                "gcode" => font.text_to_gcode(&text, &GcodeOptions::default())?,
+                "hpgl" => font.text_to_hpgl(&text, &HpglOptions::default()),
+                _ => bail!("Unknown render format {}", format),
+            };
+
+            write_output(output.as_deref(), &contents)
+        }
+        Command::Convert { font, to, output } => {
+            let font = load_font(&font)?;
+            let contents = match to.as_str() {
+                "jhf" => font.to_jhf()?,
+                "json" => font.to_json(),
+                _ => bail!("Unknown conversion format {}", to),
+            };
+
+            write_output(output.as_deref(), &contents)
+        }
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    if let Err(e) = parse_args(&args).and_then(run) {
+        eprintln!("{:#}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_works() {
+        assert_eq!(
+            parse_args(&args(&[
+                "render",
+                "futural.jhf",
+                "Hello",
+                "--svg",
+                "out.svg"
+            ]))
+            .unwrap(),
+            Command::Render {
+                font: "futural.jhf".to_string(),
+                text: "Hello".to_string(),
+                format: "svg".to_string(),
+                output: Some("out.svg".to_string()),
+            }
+        );
+        assert_eq!(
+            parse_args(&args(&["convert", "futural.jhf", "--to", "json"])).unwrap(),
+            Command::Convert {
+                font: "futural.jhf".to_string(),
+                to: "json".to_string(),
+                output: None,
+            }
+        );
+        assert!(parse_args(&args(&["info"])).is_err());
+        assert!(parse_args(&args(&["render", "futural.jhf"])).is_err());
+        assert!(parse_args(&args(&["info", "futural.jhf", "--bogus"])).is_err());
+        assert!(parse_args(&args(&["info", "futural.jhf", "extra"])).is_err());
+        assert!(parse_args(&args(&["render", "futural.jhf", "Hello", "World"])).is_err());
+    }
+
+    #[test]
+    fn info_works() {
+        let font = HersheyFont::new("name: futural\n  699  1JZ\n  714  3MWRHR\\").unwrap();
+
+        assert_eq!(
+            info(&font),
+            "Name: futural\nGlyphs: 2\nBounds: left 0, top -10, right 0, bottom 10\nPaths: 1\nVertices: 2\n"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use crate::layout::TextLayout;
+use crate::smooth::{smooth_path, CurvePath, SmoothOptions};
+use crate::{Edge, HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl Bounds {
+    pub fn width(&self) -> f64 {
+        self.right - self.left
+    }
+
+    pub fn height(&self) -> f64 {
+        self.bottom - self.top
+    }
+
+    pub fn expand(&self, amount: f64) -> Bounds {
+        Bounds {
+            top: self.top - amount,
+            right: self.right + amount,
+            bottom: self.bottom + amount,
+            left: self.left - amount,
+        }
+    }
+
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        Bounds {
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+            left: self.left.min(other.left),
+        }
+    }
+}
+
+impl From<PointF> for Bounds {
+    fn from(point: PointF) -> Bounds {
+        Bounds {
+            top: point.y,
+            right: point.x,
+            bottom: point.y,
+            left: point.x,
+        }
+    }
+}
+
+pub(crate) fn edges_bounds<'a>(edges: impl IntoIterator<Item = &'a Edge>) -> Option<Bounds> {
+    points_bounds(edges.into_iter().copied().map(PointF::from))
+}
+
+pub(crate) fn points_bounds(points: impl IntoIterator<Item = PointF>) -> Option<Bounds> {
+    points.into_iter().fold(None, |accum, point| {
+        let point = Bounds::from(point);
+
+        Some(match accum {
+            Some(bounds) => point.union(&bounds),
+            None => point,
+        })
+    })
+}
+
+impl HersheyGlyph {
+    pub fn ink_bounds(&self) -> Option<Bounds> {
+        edges_bounds(self.paths.iter().flatten())
+    }
+
+    pub fn stroke_bounds(&self, stroke_width: f64) -> Option<Bounds> {
+        self.ink_bounds()
+            .map(|bounds| bounds.expand(stroke_width / 2.0))
+    }
+
+    pub fn smooth_bounds(&self, options: &SmoothOptions) -> Option<Bounds> {
+        self.to_beziers(options)
+            .iter()
+            .map(CurvePath::bounds)
+            .reduce(|accum, bounds| accum.union(&bounds))
+    }
+
+    pub fn smooth_stroke_bounds(
+        &self,
+        stroke_width: f64,
+        options: &SmoothOptions,
+    ) -> Option<Bounds> {
+        self.smooth_bounds(options)
+            .map(|bounds| bounds.expand(stroke_width / 2.0))
+    }
+}
+
+impl HersheyFont {
+    pub(crate) fn measured_cap_height(&self) -> Option<f64> {
+        self.cap_height()
+            .filter(|height| *height > 0)
+            .map(f64::from)
+    }
+
+    pub fn ink_bounds(&self) -> Option<Bounds> {
+        edges_bounds(
+            self.glyphs
+                .iter()
+                .flat_map(|glyph| glyph.paths.iter().flatten()),
+        )
+    }
+
+    pub fn stroke_bounds(&self, stroke_width: f64) -> Option<Bounds> {
+        self.ink_bounds()
+            .map(|bounds| bounds.expand(stroke_width / 2.0))
+    }
+
+    pub fn smooth_stroke_bounds(
+        &self,
+        stroke_width: f64,
+        options: &SmoothOptions,
+    ) -> Option<Bounds> {
+        self.glyphs
+            .iter()
+            .filter_map(|glyph| glyph.smooth_bounds(options))
+            .reduce(|accum, bounds| accum.union(&bounds))
+            .map(|bounds| bounds.expand(stroke_width / 2.0))
+    }
+}
+
+impl TextLayout {
+    pub fn smooth_stroke_bounds(
+        &self,
+        stroke_width: f64,
+        options: &SmoothOptions,
+    ) -> Option<Bounds> {
+        self.glyphs
+            .iter()
+            .flat_map(|glyph| glyph.paths.iter())
+            .filter_map(|path| {
+                let points = path.iter().copied().map(PointF::from).collect::<Vec<_>>();
+
+                smooth_path(&points, options)
+            })
+            .map(|curve| curve.bounds())
+            .reduce(|accum, bounds| accum.union(&bounds))
+            .map(|bounds| bounds.expand(stroke_width / 2.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stroke_bounds_works() {
+        let font = HersheyFont::new("  720  3G][BIb").unwrap();
+        let bounds = font.get_glyph(' ').unwrap().stroke_bounds(2.0).unwrap();
+
+        assert_eq!(
+            bounds,
+            Bounds {
+                top: -17.0,
+                right: 10.0,
+                bottom: 17.0,
+                left: -10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn stroke_bounds_returns_none_for_empty_glyph() {
+        let font = HersheyFont::new("  720  1JZ").unwrap();
+
+        assert_eq!(font.get_glyph(' ').unwrap().stroke_bounds(2.0), None);
+        assert_eq!(font.stroke_bounds(2.0), None);
+    }
+
+    #[test]
+    fn smooth_stroke_bounds_include_control_points() {
+        let glyph = HersheyGlyph::new(
+            -5,
+            5,
+            vec![vec![
+                Edge { x: 0, y: 0 },
+                Edge { x: 10, y: 0 },
+                Edge { x: 10, y: 10 },
+                Edge { x: 0, y: 10 },
+            ]],
+        );
+        let options = SmoothOptions {
+            corner_angle_degrees: 180.0,
+            ..SmoothOptions::default()
+        };
+        let bounds = glyph.smooth_stroke_bounds(2.0, &options).unwrap();
+
+        assert_eq!(glyph.stroke_bounds(2.0).unwrap().right, 11.0);
+        assert_eq!(bounds.left, -1.0);
+        assert!((bounds.right - (11.0 + 10.0 / 6.0)).abs() < 1e-9);
+        assert!((bounds.top - (-1.0 - 10.0 / 6.0)).abs() < 1e-9);
+        assert!((bounds.bottom - (11.0 + 10.0 / 6.0)).abs() < 1e-9);
+        let font = HersheyFont::new("  720  3G][BIb").unwrap();
+
+        assert_eq!(
+            font.layout_text(" ")
+                .smooth_stroke_bounds(2.0, &options)
+                .map(|bounds| bounds.width()),
+            Some(20.0)
+        );
+        assert_eq!(
+            font.smooth_stroke_bounds(2.0, &options),
+            Some(Bounds {
+                top: -17.0,
+                right: 10.0,
+                bottom: 17.0,
+                left: -10.0,
+            })
+        );
+    }
+
+    #[test]
+    fn expand_works() {
+        let bounds = Bounds {
+            top: 0.0,
+            right: 1.0,
+            bottom: 1.0,
+            left: 0.0,
+        }
+        .expand(0.5);
+
+        assert_eq!(bounds.width(), 2.0);
+        assert_eq!(bounds.height(), 2.0);
+    }
+}
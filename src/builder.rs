@@ -0,0 +1,165 @@
+use crate::coordinates::CoordinateSystem;
+use crate::subset::font_from_mapped_glyphs;
+use crate::{HersheyFont, HersheyGlyph};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HersheyFontBuilder {
+    metadata: BTreeMap<String, String>,
+    glyphs: BTreeMap<char, HersheyGlyph>,
+    coordinate_system: CoordinateSystem,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyFontBuildError {
+    #[error("{0}")]
+    InvalidChar(String),
+}
+
+impl HersheyFontBuilder {
+    pub fn new() -> HersheyFontBuilder {
+        HersheyFontBuilder::default()
+    }
+
+    pub fn metadata(mut self, key: &str, value: &str) -> HersheyFontBuilder {
+        self.metadata
+            .insert(key.to_ascii_lowercase(), value.to_string());
+        self
+    }
+
+    pub fn glyph(mut self, c: char, glyph: HersheyGlyph) -> HersheyFontBuilder {
+        self.glyphs.insert(c, glyph);
+        self
+    }
+
+    pub fn remove_glyph(mut self, c: char) -> HersheyFontBuilder {
+        self.glyphs.remove(&c);
+        self
+    }
+
+    pub fn set_bearings(mut self, c: char, left: i32, right: i32) -> HersheyFontBuilder {
+        if let Some(glyph) = self.glyphs.get_mut(&c) {
+            glyph.set_bearings(left, right);
+        }
+
+        self
+    }
+
+    pub fn get_glyph(&self, c: char) -> Option<&HersheyGlyph> {
+        self.glyphs.get(&c)
+    }
+
+    pub fn build(self) -> Result<HersheyFont, HersheyFontBuildError> {
+        if let Some(c) = self.glyphs.keys().find(|c| c.is_control()) {
+            return Err(HersheyFontBuildError::InvalidChar(format!(
+                "Glyph for control char {:?} cannot be mapped",
+                c
+            )));
+        }
+
+        let mut font = font_from_mapped_glyphs(self.metadata, self.glyphs);
+
+        font.coordinate_system = self.coordinate_system;
+        Ok(font)
+    }
+}
+
+impl HersheyFont {
+    pub fn to_builder(&self) -> HersheyFontBuilder {
+        HersheyFontBuilder {
+            metadata: self.metadata.clone(),
+            glyphs: self
+                .mapped_glyphs()
+                .map(|(c, glyph)| (c, glyph.clone()))
+                .collect(),
+            coordinate_system: self.coordinate_system,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapping::CharMapping;
+    use crate::Edge;
+    use std::collections::HashMap;
+
+    #[test]
+    fn build_works() {
+        let font = HersheyFontBuilder::new()
+            .metadata("Name", "custom")
+            .glyph(
+                '"',
+                HersheyGlyph::new(
+                    -4,
+                    4,
+                    vec![vec![Edge { x: 0, y: -12 }, Edge { x: 0, y: 9 }]],
+                ),
+            )
+            .glyph(' ', HersheyGlyph::new(-8, 8, Vec::new()))
+            .set_bearings(' ', -6, 6)
+            .build()
+            .unwrap();
+
+        assert_eq!(font.meta().unwrap().name.as_deref(), Some("custom"));
+        assert_eq!(font.get_glyph(' ').unwrap().right, 6);
+        assert!(font.get_glyph('!').is_err());
+        assert_eq!((font.top, font.bottom), (-12, 9));
+    }
+
+    #[test]
+    fn build_maps_sparse_chars_without_placeholders() {
+        let font = HersheyFontBuilder::new()
+            .glyph('A', HersheyGlyph::new(-4, 4, Vec::new()))
+            .glyph('🙂', HersheyGlyph::new(-8, 8, Vec::new()))
+            .build()
+            .unwrap();
+
+        assert_eq!(font.len(), 2);
+        assert_eq!(font.get_glyph('🙂').unwrap().right, 8);
+        assert!(font.get_glyph('B').is_err());
+    }
+
+    #[test]
+    fn to_builder_keeps_mapping_and_metadata() {
+        let mut font = HersheyFont::new("name: test\n  699  1JZ\n  714  3JZRHR\\")
+            .unwrap()
+            .with_mapping(CharMapping::Custom(HashMap::from([('é', 1), ('a', 0)])));
+        font.coordinate_system = CoordinateSystem::TopLeft;
+
+        let rebuilt = font.to_builder().build().unwrap();
+
+        assert_eq!(rebuilt.get_glyph('é').unwrap().number, 714);
+        assert_eq!(rebuilt.get_glyph('a').unwrap().number, 699);
+        assert!(rebuilt.get_glyph(' ').is_err());
+        assert_eq!(rebuilt.meta(), font.meta());
+        assert_eq!(rebuilt.coordinate_system, CoordinateSystem::TopLeft);
+    }
+
+    #[test]
+    fn to_builder_allows_replacing_and_removing_glyphs() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\\n  720  3JZRHR\\").unwrap();
+        let edited = font
+            .to_builder()
+            .remove_glyph('"')
+            .glyph(
+                '!',
+                HersheyGlyph::new(-2, 2, vec![vec![Edge { x: 0, y: -3 }, Edge { x: 0, y: 3 }]]),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(edited.get_glyph('!').unwrap().bottom, 3);
+        assert!(edited.get_glyph('"').is_err());
+        assert_eq!((edited.top, edited.bottom), (-3, 3));
+    }
+
+    #[test]
+    fn build_returns_error_for_control_chars() {
+        let result = HersheyFontBuilder::new()
+            .glyph('\n', HersheyGlyph::new(0, 0, Vec::new()))
+            .build();
+
+        assert!(matches!(result, Err(HersheyFontBuildError::InvalidChar(_))));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Edge, HersheyGlyph};
+
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Fnv1a {
+        Fnv1a(0xcbf29ce484222325)
+    }
+
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    pub(crate) fn write_i32(&mut self, value: i32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn edge_key(edge: &Edge) -> (i32, i32) {
+    (edge.x, edge.y)
+}
+
+fn canonical_path(path: &[Edge]) -> Vec<Edge> {
+    let forward = path.to_vec();
+    let mut reversed = forward.clone();
+    reversed.reverse();
+
+    if reversed
+        .iter()
+        .map(edge_key)
+        .lt(forward.iter().map(edge_key))
+    {
+        reversed
+    } else {
+        forward
+    }
+}
+
+impl HersheyGlyph {
+    pub(crate) fn canonical_paths(&self) -> Vec<Vec<Edge>> {
+        let mut paths = self
+            .paths
+            .iter()
+            .map(|path| canonical_path(path))
+            .collect::<Vec<_>>();
+
+        paths.sort_by(|a, b| a.iter().map(edge_key).cmp(b.iter().map(edge_key)));
+        paths
+    }
+
+    pub fn geometry_eq(&self, other: &HersheyGlyph) -> bool {
+        self.canonical_paths() == other.canonical_paths()
+    }
+
+    pub fn canonical_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+
+        for path in self.canonical_paths() {
+            hasher.write_u64(path.len() as u64);
+
+            for edge in path {
+                hasher.write_i32(edge.x);
+                hasher.write_i32(edge.y);
+            }
+        }
+
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph(paths: Vec<Vec<Edge>>) -> HersheyGlyph {
+        HersheyGlyph::new(0, 0, paths)
+    }
+
+    #[test]
+    fn geometry_eq_ignores_order_and_direction() {
+        let a = Edge { x: 0, y: 0 };
+        let b = Edge { x: 1, y: 2 };
+        let c = Edge { x: 3, y: 1 };
+        let first = glyph(vec![vec![a, b], vec![b, c]]);
+        let second = glyph(vec![vec![c, b], vec![b, a]]);
+
+        assert!(first.geometry_eq(&second));
+        assert_eq!(first.canonical_hash(), second.canonical_hash());
+    }
+
+    #[test]
+    fn geometry_eq_detects_different_geometry() {
+        let a = Edge { x: 0, y: 0 };
+        let b = Edge { x: 1, y: 2 };
+        let c = Edge { x: 3, y: 1 };
+        let first = glyph(vec![vec![a, b, c]]);
+        let second = glyph(vec![vec![a, c, b]]);
+
+        assert!(!first.geometry_eq(&second));
+        assert_ne!(first.canonical_hash(), second.canonical_hash());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Edge, HersheyFont, HersheyGlyph};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StrokeClass {
+    SingleStroke,
+    MultiStroke,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FontStyle {
+    Roman,
+    Script,
+    Gothic,
+    Greek,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontClass {
+    pub stroke: StrokeClass,
+    pub style: Option<FontStyle>,
+}
+
+const PARALLEL_SINE: f64 = 0.17;
+const MIN_OFFSET: f64 = 0.5;
+const MAX_OFFSET: f64 = 2.5;
+const MIN_SEGMENT_LENGTH: f64 = 3.0;
+const MULTI_STROKE_GLYPH_RATIO: f64 = 0.3;
+
+fn is_doubled(a: (&Edge, &Edge), b: (&Edge, &Edge)) -> bool {
+    let (ax, ay) = ((a.1.x - a.0.x) as f64, (a.1.y - a.0.y) as f64);
+    let (bx, by) = ((b.1.x - b.0.x) as f64, (b.1.y - b.0.y) as f64);
+    let a_length = ax.hypot(ay);
+    let b_length = bx.hypot(by);
+
+    if a_length < MIN_SEGMENT_LENGTH || b_length < MIN_SEGMENT_LENGTH {
+        return false;
+    }
+
+    if (ax * by - ay * bx).abs() / (a_length * b_length) > PARALLEL_SINE {
+        return false;
+    }
+
+    let (mx, my) = (
+        (b.0.x + b.1.x) as f64 / 2.0 - a.0.x as f64,
+        (b.0.y + b.1.y) as f64 / 2.0 - a.0.y as f64,
+    );
+    let offset = (ax * my - ay * mx).abs() / a_length;
+
+    if !(MIN_OFFSET..=MAX_OFFSET).contains(&offset) {
+        return false;
+    }
+
+    let project =
+        |edge: &Edge| ((edge.x - a.0.x) as f64 * ax + (edge.y - a.0.y) as f64 * ay) / a_length;
+    let (t0, t1) = (project(b.0), project(b.1));
+
+    t0.max(t1) > 0.0 && t0.min(t1) < a_length
+}
+
+impl HersheyGlyph {
+    pub(crate) fn has_doubled_strokes(&self) -> bool {
+        let segments = self
+            .paths
+            .iter()
+            .flat_map(|path| path.windows(2).map(|pair| (&pair[0], &pair[1])))
+            .collect::<Vec<_>>();
+
+        segments.iter().enumerate().any(|(i, a)| {
+            segments[i + 1..]
+                .iter()
+                .any(|b| a.1 != b.0 && a.0 != b.1 && is_doubled(*a, *b))
+        })
+    }
+}
+
+fn style_from_name(name: &str) -> Option<FontStyle> {
+    let name = name.to_ascii_lowercase();
+
+    if name.contains("script") || name.contains("cursive") {
+        Some(FontStyle::Script)
+    } else if name.contains("gothic") {
+        Some(FontStyle::Gothic)
+    } else if name.contains("greek") {
+        Some(FontStyle::Greek)
+    } else if name.contains("roman") || name.contains("rowman") || name.contains("futura") {
+        Some(FontStyle::Roman)
+    } else {
+        None
+    }
+}
+
+impl HersheyFont {
+    pub fn class(&self) -> FontClass {
+        let drawn = self
+            .glyphs
+            .iter()
+            .filter(|glyph| !glyph.paths.is_empty())
+            .collect::<Vec<_>>();
+        let doubled = drawn
+            .iter()
+            .filter(|glyph| glyph.has_doubled_strokes())
+            .count();
+
+        let stroke = if !drawn.is_empty()
+            && doubled as f64 / drawn.len() as f64 > MULTI_STROKE_GLYPH_RATIO
+        {
+            StrokeClass::MultiStroke
+        } else {
+            StrokeClass::SingleStroke
+        };
+
+        let style = ["style", "name", "family", "source"]
+            .iter()
+            .filter_map(|key| self.metadata.get(*key))
+            .find_map(|value| style_from_name(value));
+
+        FontClass { stroke, style }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_detects_single_stroke_fonts() {
+        let font = HersheyFont::new("name: rowmans\n  720  3JZRHR\\").unwrap();
+
+        assert_eq!(
+            font.class(),
+            FontClass {
+                stroke: StrokeClass::SingleStroke,
+                style: Some(FontStyle::Roman),
+            }
+        );
+    }
+
+    #[test]
+    fn class_detects_multi_stroke_fonts() {
+        let font = HersheyFont::new("name: scriptc\n  720  6JZRHR\\ RSHS\\").unwrap();
+
+        assert_eq!(
+            font.class(),
+            FontClass {
+                stroke: StrokeClass::MultiStroke,
+                style: Some(FontStyle::Script),
+            }
+        );
+    }
+
+    #[test]
+    fn style_from_name_works() {
+        assert_eq!(style_from_name("gothiceng"), Some(FontStyle::Gothic));
+        assert_eq!(style_from_name("greekc"), Some(FontStyle::Greek));
+        assert_eq!(style_from_name("symbolic"), None);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::parallel::thread_count;
+use crate::HersheyFont;
+use std::cell::OnceCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+const EXTENSIONS: [&str; 2] = ["jhf", "dat"];
+
+#[derive(Debug)]
+struct FontEntry {
+    path: PathBuf,
+    font: OnceCell<HersheyFont>,
+}
+
+#[derive(Debug, Default)]
+pub struct FontCollection {
+    entries: BTreeMap<String, FontEntry>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum FontCollectionLoadError {
+    #[error("{1}")]
+    IoError(#[source] std::io::Error, String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum FontCollectionGetError {
+    #[error("{0}")]
+    FontNotFound(String),
+    #[error("{1}")]
+    IoError(#[source] std::io::Error, String),
+    #[error("{1}")]
+    ParseError(#[source] crate::HersheyFontNewError, String),
+}
+
+pub fn load_dir(path: impl AsRef<Path>) -> Result<FontCollection, FontCollectionLoadError> {
+    let path = path.as_ref();
+    let read_error =
+        |e| FontCollectionLoadError::IoError(e, format!("Error reading {}", path.display()));
+    let mut entries = BTreeMap::new();
+
+    for entry in fs::read_dir(path).map_err(read_error)? {
+        let file = entry.map_err(read_error)?.path();
+        let is_font = file
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                EXTENSIONS
+                    .iter()
+                    .any(|known| extension.eq_ignore_ascii_case(known))
+            });
+
+        if !is_font || !file.is_file() {
+            continue;
+        }
+
+        if let Some(stem) = file.file_stem().and_then(|stem| stem.to_str()) {
+            entries.insert(
+                stem.to_string(),
+                FontEntry {
+                    path: file.clone(),
+                    font: OnceCell::new(),
+                },
+            );
+        }
+    }
+
+    Ok(FontCollection { entries })
+}
+
+impl FontCollection {
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    pub fn path(&self, name: &str) -> Option<&Path> {
+        self.entries.get(name).map(|entry| entry.path.as_path())
+    }
+
+    pub fn get(&self, name: &str) -> Result<&HersheyFont, FontCollectionGetError> {
+        let entry = self
+            .entries
+            .get(name)
+            .ok_or(FontCollectionGetError::FontNotFound(format!(
+                "Font {} not found in collection",
+                name
+            )))?;
+
+        if let Some(font) = entry.font.get() {
+            return Ok(font);
+        }
+
+        let font = read_font(&entry.path)?;
+
+        Ok(entry.font.get_or_init(|| font))
+    }
+
+    pub fn load_all(&self) -> Result<(), FontCollectionGetError> {
+        let pending = self
+            .entries
+            .values()
+            .filter(|entry| entry.font.get().is_none())
+            .collect::<Vec<_>>();
+        let paths = pending
+            .iter()
+            .map(|entry| entry.path.as_path())
+            .collect::<Vec<_>>();
+        let chunk_size = paths.len().div_ceil(thread_count(paths.len())).max(1);
+
+        let fonts = thread::scope(|scope| {
+            paths
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope
+                        .spawn(move || chunk.iter().map(|path| read_font(path)).collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("font loading thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        let mut first_error = None;
+
+        for (entry, font) in pending.into_iter().zip(fonts) {
+            match font {
+                Ok(font) => {
+                    let _ = entry.font.set(font);
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+fn read_font(path: &Path) -> Result<HersheyFont, FontCollectionGetError> {
+    let data = fs::read_to_string(path).map_err(|e| {
+        FontCollectionGetError::IoError(e, format!("Error reading {}", path.display()))
+    })?;
+
+    HersheyFont::new(&data).map_err(|e| {
+        FontCollectionGetError::ParseError(e, format!("Error parsing {}", path.display()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("hershey-parser-{}-{}", name, std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn load_dir_works() {
+        let dir = temp_dir("load-dir");
+        fs::write(dir.join("futural.jhf"), "  720  3G][BIb").unwrap();
+        fs::write(dir.join("broken.jhf"), " ").unwrap();
+        fs::write(dir.join("notes.txt"), "not a font").unwrap();
+
+        let collection = load_dir(&dir).unwrap();
+
+        assert_eq!(
+            collection.names().collect::<Vec<_>>(),
+            vec!["broken", "futural"]
+        );
+        assert!(collection.get("futural").unwrap().get_glyph(' ').is_ok());
+        assert!(matches!(
+            collection.get("broken"),
+            Err(FontCollectionGetError::ParseError(_, _))
+        ));
+        assert!(matches!(
+            collection.get("notes"),
+            Err(FontCollectionGetError::FontNotFound(_))
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn load_all_parses_every_font() {
+        let dir = temp_dir("load-all");
+        fs::write(dir.join("futural.jhf"), "  720  3G][BIb").unwrap();
+        fs::write(dir.join("rowmans.jhf"), "  699  1JZ").unwrap();
+
+        let collection = load_dir(&dir).unwrap();
+        collection.load_all().unwrap();
+
+        assert!(collection
+            .entries
+            .values()
+            .all(|entry| entry.font.get().is_some()));
+        assert_eq!(collection.get("rowmans").unwrap().len(), 1);
+
+        fs::write(dir.join("broken.jhf"), " ").unwrap();
+
+        let collection = load_dir(&dir).unwrap();
+
+        assert!(matches!(
+            collection.load_all(),
+            Err(FontCollectionGetError::ParseError(_, _))
+        ));
+        assert!(collection.entries["futural"].font.get().is_some());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn load_dir_returns_error_if_directory_is_missing() {
+        let dir = temp_dir("missing");
+        fs::remove_dir_all(&dir).unwrap();
+
+        let result = load_dir(&dir);
+
+        assert!(matches!(
+            result,
+            Err(FontCollectionLoadError::IoError(_, _))
+        ));
+    }
+}
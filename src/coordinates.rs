@@ -0,0 +1,161 @@
+use crate::layout::TextLayout;
+use crate::{Edge, HersheyFont, HersheyGlyph};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CoordinateSystem {
+    #[default]
+    Hershey,
+    BaselineLeft,
+    TopLeft,
+}
+
+impl HersheyFont {
+    pub fn baseline(&self) -> i32 {
+        self.get_glyph_checked('H')
+            .filter(|glyph| !glyph.paths.is_empty())
+            .map_or(0, |glyph| glyph.bottom)
+    }
+
+    pub(crate) fn y_up(&self) -> bool {
+        self.coordinate_system == CoordinateSystem::BaselineLeft
+    }
+
+    pub(crate) fn y_transform(&self) -> (i32, i32) {
+        match self.coordinate_system {
+            CoordinateSystem::Hershey => (1, 0),
+            CoordinateSystem::BaselineLeft => (-1, self.baseline()),
+            CoordinateSystem::TopLeft => (1, -self.top),
+        }
+    }
+
+    pub fn convert_point(&self, glyph: &HersheyGlyph, edge: Edge) -> Edge {
+        let (sign, offset) = self.y_transform();
+
+        match self.coordinate_system {
+            CoordinateSystem::Hershey => edge,
+            _ => Edge {
+                x: edge.x - glyph.left,
+                y: sign * edge.y + offset,
+            },
+        }
+    }
+
+    pub fn glyph_paths(&self, glyph: char) -> Option<Vec<Vec<Edge>>> {
+        let glyph = self.get_glyph_checked(glyph)?;
+
+        Some(
+            glyph
+                .paths
+                .iter()
+                .map(|path| {
+                    path.iter()
+                        .map(|edge| self.convert_point(glyph, *edge))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl TextLayout {
+    pub(crate) fn convert_coordinates(&mut self, font: &HersheyFont) {
+        let (sign, offset) = font.y_transform();
+
+        for glyph in &mut self.glyphs {
+            glyph.y *= sign;
+            glyph
+                .paths
+                .iter_mut()
+                .flatten()
+                .for_each(|edge| edge.y = sign * edge.y + offset);
+        }
+
+        for line in &mut self.lines {
+            line.y *= sign;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::HersheyFontBuilder;
+
+    fn font() -> HersheyFont {
+        HersheyFontBuilder::new()
+            .glyph(
+                ' ',
+                HersheyGlyph::new(
+                    -11,
+                    11,
+                    vec![vec![Edge { x: 9, y: -16 }, Edge { x: -9, y: 16 }]],
+                ),
+            )
+            .glyph(
+                'H',
+                HersheyGlyph::new(
+                    -8,
+                    8,
+                    vec![vec![Edge { x: 0, y: -10 }, Edge { x: 0, y: 10 }]],
+                ),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn glyph_paths_uses_hershey_coordinates_by_default() {
+        let font = font();
+
+        assert_eq!(
+            font.glyph_paths(' ').unwrap(),
+            font.get_glyph(' ').unwrap().paths
+        );
+    }
+
+    #[test]
+    fn glyph_paths_converts_to_baseline_left() {
+        let mut font = font();
+        font.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            font.glyph_paths('H').unwrap(),
+            vec![vec![Edge { x: 8, y: 20 }, Edge { x: 8, y: 0 }]]
+        );
+    }
+
+    #[test]
+    fn glyph_paths_converts_to_top_left() {
+        let mut font = font();
+        font.coordinate_system = CoordinateSystem::TopLeft;
+
+        assert_eq!(
+            font.glyph_paths(' ').unwrap(),
+            vec![vec![Edge { x: 20, y: 0 }, Edge { x: 2, y: 32 }]]
+        );
+    }
+
+    #[test]
+    fn layout_text_uses_coordinate_system() {
+        let mut font = font();
+        font.coordinate_system = CoordinateSystem::BaselineLeft;
+        let layout = font.layout_text("H\nH");
+
+        assert_eq!(layout.positions(), vec![('H', 0, 0), ('H', 0, -32)]);
+        assert_eq!(
+            layout.paths(),
+            vec![
+                vec![Edge { x: 8, y: 20 }, Edge { x: 8, y: 0 }],
+                vec![Edge { x: 8, y: -12 }, Edge { x: 8, y: -32 }],
+            ]
+        );
+        assert_eq!(layout.lines[1].y, -32);
+
+        font.coordinate_system = CoordinateSystem::TopLeft;
+
+        assert_eq!(
+            font.layout_text("H").paths(),
+            vec![vec![Edge { x: 8, y: 6 }, Edge { x: 8, y: 26 }]]
+        );
+    }
+}
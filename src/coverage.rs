@@ -0,0 +1,164 @@
+use crate::HersheyFont;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingChar {
+    pub character: char,
+    pub occurrences: usize,
+    pub fallback: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoverageReport {
+    pub missing: Vec<MissingChar>,
+}
+
+impl CoverageReport {
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+fn decompose(c: char) -> Option<&'static str> {
+    Some(match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => "A",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => "a",
+        'Ç' => "C",
+        'ç' => "c",
+        'È' | 'É' | 'Ê' | 'Ë' => "E",
+        'è' | 'é' | 'ê' | 'ë' => "e",
+        'Ì' | 'Í' | 'Î' | 'Ï' => "I",
+        'ì' | 'í' | 'î' | 'ï' => "i",
+        'Ñ' => "N",
+        'ñ' => "n",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => "O",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => "o",
+        'Ù' | 'Ú' | 'Û' | 'Ü' => "U",
+        'ù' | 'ú' | 'û' | 'ü' => "u",
+        'Ý' => "Y",
+        'ý' | 'ÿ' => "y",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'ß' => "ss",
+        '‘' | '’' | '‚' | '′' => "'",
+        '“' | '”' | '„' | '″' => "\"",
+        '–' | '—' | '−' => "-",
+        '…' => "...",
+        '\u{a0}' => " ",
+        _ => return None,
+    })
+}
+
+impl HersheyFont {
+    fn can_render(&self, text: &str) -> bool {
+        text.chars().all(|c| self.get_glyph_checked(c).is_some())
+    }
+
+    fn fallback_for(&self, c: char) -> Option<String> {
+        let decomposed = decompose(c).unwrap_or_default();
+        let candidates = [
+            c.to_lowercase().collect::<String>(),
+            c.to_uppercase().collect::<String>(),
+            decomposed.to_string(),
+            decomposed.to_lowercase(),
+            decomposed.to_uppercase(),
+        ];
+
+        candidates.into_iter().find(|candidate| {
+            !candidate.is_empty() && *candidate != c.to_string() && self.can_render(candidate)
+        })
+    }
+
+    pub fn check_coverage(&self, text: &str) -> CoverageReport {
+        let mut missing: Vec<MissingChar> = Vec::new();
+
+        for c in text.chars().filter(|c| *c != '\n' && *c != '\r') {
+            if self.get_glyph_checked(c).is_some() {
+                continue;
+            }
+
+            match missing.iter_mut().find(|m| m.character == c) {
+                Some(entry) => entry.occurrences += 1,
+                None => missing.push(MissingChar {
+                    character: c,
+                    occurrences: 1,
+                    fallback: self.fallback_for(c),
+                }),
+            }
+        }
+
+        CoverageReport { missing }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font() -> HersheyFont {
+        let glyph = "  720  3G][BIb";
+
+        HersheyFont::new(&vec![glyph; 96].join("\n")).unwrap()
+    }
+
+    #[test]
+    fn check_coverage_works() {
+        let font = HersheyFont::new(&vec!["  720  3G][BIb"; 60].join("\n")).unwrap();
+        let report = font.check_coverage("Hello, Wörld");
+
+        assert_eq!(
+            report.missing,
+            vec![
+                MissingChar {
+                    character: 'e',
+                    occurrences: 1,
+                    fallback: Some("E".to_string()),
+                },
+                MissingChar {
+                    character: 'l',
+                    occurrences: 3,
+                    fallback: Some("L".to_string()),
+                },
+                MissingChar {
+                    character: 'o',
+                    occurrences: 1,
+                    fallback: Some("O".to_string()),
+                },
+                MissingChar {
+                    character: 'ö',
+                    occurrences: 1,
+                    fallback: Some("O".to_string()),
+                },
+                MissingChar {
+                    character: 'r',
+                    occurrences: 1,
+                    fallback: Some("R".to_string()),
+                },
+                MissingChar {
+                    character: 'd',
+                    occurrences: 1,
+                    fallback: Some("D".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_coverage_reports_missing_fallbacks() {
+        let report = font().check_coverage("π");
+
+        assert_eq!(report.missing[0].fallback, None);
+    }
+
+    #[test]
+    fn check_coverage_suggests_decomposed_fallbacks() {
+        let report = font().check_coverage("café…");
+
+        assert_eq!(report.missing[0].fallback, Some("e".to_string()));
+        assert_eq!(report.missing[1].fallback, Some("...".to_string()));
+    }
+
+    #[test]
+    fn check_coverage_is_complete_for_covered_text() {
+        assert!(font().check_coverage("Hello\n").is_complete());
+    }
+}
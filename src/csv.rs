@@ -0,0 +1,69 @@
+use crate::HersheyFont;
+use std::io::{self, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Delimiter {
+    Comma,
+    Tab,
+}
+
+impl Delimiter {
+    fn as_char(&self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Tab => '\t',
+        }
+    }
+}
+
+impl HersheyFont {
+    pub fn write_segments<W: Write>(&self, mut writer: W, delimiter: Delimiter) -> io::Result<()> {
+        let d = delimiter.as_char();
+
+        writeln!(writer, "glyph{d}path{d}x1{d}y1{d}x2{d}y2")?;
+
+        for (glyph_index, glyph) in self.glyphs.iter().enumerate() {
+            for (path_index, path) in glyph.paths.iter().enumerate() {
+                for pair in path.windows(2) {
+                    writeln!(
+                        writer,
+                        "{}{d}{}{d}{}{d}{}{d}{}{d}{}",
+                        glyph_index, path_index, pair[0].x, pair[0].y, pair[1].x, pair[1].y
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_segments_works() {
+        let font = HersheyFont::new("  720  3G][BIb").unwrap();
+        let mut output = Vec::new();
+
+        font.write_segments(&mut output, Delimiter::Comma).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "glyph,path,x1,y1,x2,y2\n0,0,9,-16,-9,16\n"
+        );
+    }
+
+    #[test]
+    fn write_segments_supports_tabs() {
+        let font = HersheyFont::new("  720  3G][BIb").unwrap();
+        let mut output = Vec::new();
+
+        font.write_segments(&mut output, Delimiter::Tab).unwrap();
+
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .starts_with("glyph\tpath\tx1\ty1\tx2\ty2\n"));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::transform::Transform2D;
+use crate::{Edge, HersheyFont, HersheyGlyph};
+
+fn derived_glyph(
+    glyph: &HersheyGlyph,
+    transform: &Transform2D,
+    left: i32,
+    right: i32,
+) -> HersheyGlyph {
+    let paths = glyph
+        .paths
+        .iter()
+        .map(|path| {
+            path.iter()
+                .map(|edge| {
+                    let point = transform.apply_edge(edge);
+
+                    Edge {
+                        x: point.x.round() as i32,
+                        y: point.y.round() as i32,
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let mut derived = HersheyGlyph::new(left, right, paths);
+
+    derived.number = glyph.number;
+    derived
+}
+
+impl HersheyFont {
+    pub(crate) fn with_glyphs(&self, glyphs: Vec<HersheyGlyph>) -> HersheyFont {
+        let mut font = HersheyFont::from_parts(self.metadata.clone(), glyphs);
+
+        font.coordinate_system = self.coordinate_system;
+        font.mapping = self.mapping.clone();
+        font
+    }
+
+    pub fn slanted(&self, shear: f64) -> HersheyFont {
+        let transform = Transform2D {
+            c: -shear,
+            e: shear * self.baseline() as f64,
+            ..Transform2D::identity()
+        };
+
+        self.with_glyphs(
+            self.glyphs
+                .iter()
+                .map(|glyph| derived_glyph(glyph, &transform, glyph.left, glyph.right))
+                .collect(),
+        )
+    }
+
+    pub fn condensed(&self, factor: f64) -> HersheyFont {
+        let transform = Transform2D::scale(factor, 1.0);
+        let scale = |x: i32| (x as f64 * factor).round() as i32;
+
+        self.with_glyphs(
+            self.glyphs
+                .iter()
+                .map(|glyph| {
+                    derived_glyph(glyph, &transform, scale(glyph.left), scale(glyph.right))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn small_caps(&self) -> HersheyFont {
+        let (Some(x_height), Some(cap_height)) = (self.x_height(), self.cap_height()) else {
+            return self.clone();
+        };
+        let factor = x_height as f64 / cap_height as f64;
+        let baseline = self.baseline() as f64;
+        let transform = Transform2D::translate(0.0, -baseline)
+            .then(&Transform2D::scale(factor, factor))
+            .then(&Transform2D::translate(0.0, baseline));
+        let scale = |x: i32| (x as f64 * factor).round() as i32;
+        let mut glyphs = self.glyphs.clone();
+
+        for lower in 'a'..='z' {
+            let upper = lower.to_ascii_uppercase();
+            let (Some(index), Some(glyph)) = (
+                self.mapping.index(&self.numbers, lower),
+                self.get_glyph_checked(upper),
+            ) else {
+                continue;
+            };
+
+            if let Some(slot) = glyphs.get_mut(index) {
+                let number = slot.number;
+
+                *slot = derived_glyph(glyph, &transform, scale(glyph.left), scale(glyph.right));
+                slot.number = number;
+            }
+        }
+
+        self.with_glyphs(glyphs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::HersheyFontBuilder;
+
+    fn stem(top: i32, bottom: i32) -> HersheyGlyph {
+        HersheyGlyph::new(
+            -4,
+            4,
+            vec![vec![Edge { x: 0, y: top }, Edge { x: 0, y: bottom }]],
+        )
+    }
+
+    fn font() -> HersheyFont {
+        HersheyFontBuilder::new()
+            .glyph('H', stem(-12, 9))
+            .glyph('x', stem(-5, 9))
+            .glyph('I', stem(-12, 9))
+            .glyph('i', HersheyGlyph::new(0, 0, Vec::new()))
+            .glyph('j', HersheyGlyph::new(0, 0, Vec::new()))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn slanted_shears_around_baseline() {
+        let glyph = font().slanted(0.25).get_glyph('H').unwrap().clone();
+
+        assert_eq!(
+            glyph.paths,
+            vec![vec![Edge { x: 5, y: -12 }, Edge { x: 0, y: 9 }]]
+        );
+        assert_eq!((glyph.left, glyph.right), (-4, 4));
+    }
+
+    #[test]
+    fn condensed_scales_horizontally() {
+        let font = HersheyFont::new("  714  3MWPHT\\").unwrap().condensed(0.5);
+        let glyph = font.get_glyph(' ').unwrap();
+
+        assert_eq!((glyph.left, glyph.right), (-3, 3));
+        assert_eq!(
+            glyph.paths,
+            vec![vec![Edge { x: -1, y: -10 }, Edge { x: 1, y: 10 }]]
+        );
+    }
+
+    #[test]
+    fn small_caps_scales_uppercase_into_lowercase() {
+        let font = font().small_caps();
+        let glyph = font.get_glyph('i').unwrap();
+
+        assert_eq!(
+            glyph.paths,
+            vec![vec![Edge { x: 0, y: -5 }, Edge { x: 0, y: 9 }]]
+        );
+        assert_eq!((glyph.left, glyph.right), (-3, 3));
+        assert!(font.get_glyph('j').unwrap().paths.is_empty());
+        assert_eq!(font.get_glyph('I').unwrap().paths[0][0].y, -12);
+    }
+}
@@ -0,0 +1,255 @@
+use crate::gcode::format_number;
+use crate::layout::TextLayout;
+use crate::units::Units;
+use crate::{Edge, HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DxfOptions {
+    pub scale: f64,
+    pub flip_y: bool,
+    pub layer: String,
+    pub units: Option<Units>,
+}
+
+impl Default for DxfOptions {
+    fn default() -> DxfOptions {
+        DxfOptions {
+            scale: 1.0,
+            flip_y: true,
+            layer: "HERSHEY".to_string(),
+            units: None,
+        }
+    }
+}
+
+fn push_pair(lines: &mut Vec<String>, code: u32, value: &str) {
+    lines.push(code.to_string());
+    lines.push(value.to_string());
+}
+
+fn drawing_units(units: Units) -> (Units, &'static str) {
+    match units {
+        Units::Inches => (Units::Inches, "1"),
+        Units::Millimeters | Units::Pixels { .. } => (Units::Millimeters, "4"),
+    }
+}
+
+fn push_point(lines: &mut Vec<String>, point: PointF, options: &DxfOptions) {
+    let scale = options.scale
+        * options
+            .units
+            .map_or(1.0, |units| units.factor(drawing_units(units).0));
+    let y = point.y * scale * if options.flip_y { -1.0 } else { 1.0 };
+
+    push_pair(lines, 10, &format_number(point.x * scale));
+    push_pair(lines, 20, &format_number(y));
+}
+
+pub fn write_dxf_points(paths: &[Vec<PointF>], options: &DxfOptions) -> String {
+    let mut lines = Vec::new();
+
+    push_pair(&mut lines, 0, "SECTION");
+    push_pair(&mut lines, 2, "HEADER");
+    push_pair(&mut lines, 9, "$ACADVER");
+    push_pair(&mut lines, 1, "AC1009");
+
+    if let Some(units) = options.units {
+        push_pair(&mut lines, 9, "$INSUNITS");
+        push_pair(&mut lines, 70, drawing_units(units).1);
+    }
+
+    let header = [
+        (0, "ENDSEC"),
+        (0, "SECTION"),
+        (2, "TABLES"),
+        (0, "TABLE"),
+        (2, "LTYPE"),
+        (70, "1"),
+        (0, "LTYPE"),
+        (2, "CONTINUOUS"),
+        (70, "0"),
+        (3, "Solid line"),
+        (72, "65"),
+        (73, "0"),
+        (40, "0.0"),
+        (0, "ENDTAB"),
+        (0, "TABLE"),
+        (2, "LAYER"),
+        (70, "1"),
+        (0, "LAYER"),
+        (2, options.layer.as_str()),
+        (70, "0"),
+        (62, "7"),
+        (6, "CONTINUOUS"),
+        (0, "ENDTAB"),
+        (0, "ENDSEC"),
+        (0, "SECTION"),
+        (2, "ENTITIES"),
+    ];
+
+    for (code, value) in header {
+        push_pair(&mut lines, code, value);
+    }
+
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        if path.len() == 1 {
+            push_pair(&mut lines, 0, "POINT");
+            push_pair(&mut lines, 8, &options.layer);
+            push_point(&mut lines, path[0], options);
+            continue;
+        }
+
+        let closed = path.len() > 2 && path.first() == path.last();
+        let vertices = if closed {
+            &path[..path.len() - 1]
+        } else {
+            &path[..]
+        };
+
+        push_pair(&mut lines, 0, "POLYLINE");
+        push_pair(&mut lines, 8, &options.layer);
+        push_pair(&mut lines, 66, "1");
+        push_pair(&mut lines, 10, "0");
+        push_pair(&mut lines, 20, "0");
+        push_pair(&mut lines, 30, "0");
+        push_pair(&mut lines, 70, if closed { "1" } else { "0" });
+
+        for point in vertices {
+            push_pair(&mut lines, 0, "VERTEX");
+            push_pair(&mut lines, 8, &options.layer);
+            push_point(&mut lines, *point, options);
+        }
+
+        push_pair(&mut lines, 0, "SEQEND");
+        push_pair(&mut lines, 8, &options.layer);
+    }
+
+    push_pair(&mut lines, 0, "ENDSEC");
+    push_pair(&mut lines, 0, "EOF");
+
+    lines.join("\n") + "\n"
+}
+
+pub fn write_dxf(paths: &[Vec<Edge>], options: &DxfOptions) -> String {
+    let points = paths
+        .iter()
+        .map(|path| path.iter().copied().map(PointF::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    write_dxf_points(&points, options)
+}
+
+impl HersheyGlyph {
+    pub fn to_dxf(&self, options: &DxfOptions) -> String {
+        write_dxf(&self.paths, options)
+    }
+}
+
+impl TextLayout {
+    pub fn to_dxf(&self, options: &DxfOptions) -> String {
+        write_dxf(&self.paths(), options)
+    }
+}
+
+impl HersheyFont {
+    pub fn text_to_dxf(&self, text: &str, options: &DxfOptions) -> String {
+        let options = DxfOptions {
+            flip_y: options.flip_y != self.y_up(),
+            ..options.clone()
+        };
+
+        self.layout_text(text).to_dxf(&options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+
+    #[test]
+    fn write_dxf_works() {
+        let paths = vec![
+            vec![Edge { x: 0, y: 0 }, Edge { x: 4, y: 2 }],
+            vec![Edge { x: 1, y: 1 }],
+        ];
+        let dxf = write_dxf(
+            &paths,
+            &DxfOptions {
+                scale: 0.5,
+                layer: "TEXT".to_string(),
+                ..DxfOptions::default()
+            },
+        );
+
+        assert!(dxf.starts_with("0\nSECTION\n2\nHEADER\n9\n$ACADVER\n1\nAC1009\n0\nENDSEC\n"));
+        assert!(dxf.contains("0\nLTYPE\n2\nCONTINUOUS\n"));
+        assert!(dxf.contains("0\nLAYER\n2\nTEXT\n70\n0\n62\n7\n6\nCONTINUOUS\n"));
+        assert!(dxf.contains(
+            "0\nPOLYLINE\n8\nTEXT\n66\n1\n10\n0\n20\n0\n30\n0\n70\n0\n0\nVERTEX\n8\nTEXT\n10\n0\n20\n0\n0\nVERTEX\n8\nTEXT\n10\n2\n20\n-1\n0\nSEQEND\n8\nTEXT\n"
+        ));
+        assert!(dxf.contains("0\nPOINT\n8\nTEXT\n10\n0.5\n20\n-0.5\n"));
+        assert!(dxf.ends_with("0\nENDSEC\n0\nEOF\n"));
+    }
+
+    #[test]
+    fn write_dxf_writes_drawing_units() {
+        let paths = vec![vec![Edge { x: 96, y: 0 }]];
+        let dxf = write_dxf(
+            &paths,
+            &DxfOptions {
+                units: Some(Units::Pixels { dpi: 96.0 }),
+                ..DxfOptions::default()
+            },
+        );
+
+        assert!(dxf.contains("9\n$ACADVER\n1\nAC1009\n9\n$INSUNITS\n70\n4\n0\nENDSEC\n"));
+        assert!(dxf.contains("0\nPOINT\n8\nHERSHEY\n10\n25.4\n20\n0\n"));
+        assert!(write_dxf(
+            &paths,
+            &DxfOptions {
+                units: Some(Units::Inches),
+                ..DxfOptions::default()
+            }
+        )
+        .contains("$INSUNITS\n70\n1\n"));
+    }
+
+    #[test]
+    fn write_dxf_closes_loops() {
+        let square = vec![vec![
+            Edge { x: 0, y: 0 },
+            Edge { x: 1, y: 0 },
+            Edge { x: 1, y: 1 },
+            Edge { x: 0, y: 0 },
+        ]];
+        let dxf = write_dxf(&square, &DxfOptions::default());
+
+        assert!(dxf.contains("30\n0\n70\n1\n"));
+        assert_eq!(dxf.matches("0\nVERTEX\n").count(), 3);
+    }
+
+    #[test]
+    fn text_layout_to_dxf_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let dxf = font.layout_text("!!").to_dxf(&DxfOptions::default());
+
+        assert_eq!(dxf.matches("0\nPOLYLINE\n").count(), 2);
+        assert_eq!(dxf.matches("0\nSEQEND\n").count(), 2);
+        assert!(dxf.contains(
+            "0\nVERTEX\n8\nHERSHEY\n10\n15\n20\n10\n0\nVERTEX\n8\nHERSHEY\n10\n15\n20\n-10\n"
+        ));
+    }
+
+    #[test]
+    fn text_to_dxf_follows_coordinate_system() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHRR").unwrap();
+        let mut y_up = font.clone();
+        y_up.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            y_up.text_to_dxf("!\n!", &DxfOptions::default()),
+            font.text_to_dxf("!\n!", &DxfOptions::default())
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Edge, HersheyGlyph};
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyGlyphEditError {
+    #[error("{0}")]
+    OutOfRange(String),
+}
+
+impl HersheyGlyph {
+    fn check_path(&self, path: usize) -> Result<(), HersheyGlyphEditError> {
+        if path < self.paths.len() {
+            Ok(())
+        } else {
+            Err(HersheyGlyphEditError::OutOfRange(format!(
+                "Path {} not found in glyph",
+                path
+            )))
+        }
+    }
+
+    fn check_vertex(&self, path: usize, index: usize) -> Result<(), HersheyGlyphEditError> {
+        self.check_path(path)?;
+
+        if index < self.paths[path].len() {
+            Ok(())
+        } else {
+            Err(HersheyGlyphEditError::OutOfRange(format!(
+                "Vertex {} not found in path {}",
+                index, path
+            )))
+        }
+    }
+
+    pub fn move_vertex(
+        &mut self,
+        path: usize,
+        index: usize,
+        to: Edge,
+    ) -> Result<(), HersheyGlyphEditError> {
+        self.check_vertex(path, index)?;
+        self.paths[path][index] = to;
+        self.update_bounds();
+
+        Ok(())
+    }
+
+    pub fn insert_vertex(
+        &mut self,
+        path: usize,
+        index: usize,
+        vertex: Edge,
+    ) -> Result<(), HersheyGlyphEditError> {
+        if path == self.paths.len() && index == 0 {
+            self.paths.push(Vec::new());
+        }
+
+        self.check_path(path)?;
+
+        if index > self.paths[path].len() {
+            return Err(HersheyGlyphEditError::OutOfRange(format!(
+                "Vertex {} not found in path {}",
+                index, path
+            )));
+        }
+
+        self.paths[path].insert(index, vertex);
+        self.update_bounds();
+
+        Ok(())
+    }
+
+    pub fn delete_vertex(
+        &mut self,
+        path: usize,
+        index: usize,
+    ) -> Result<Edge, HersheyGlyphEditError> {
+        self.check_vertex(path, index)?;
+
+        let vertex = self.paths[path].remove(index);
+
+        if self.paths[path].is_empty() {
+            self.paths.remove(path);
+        }
+
+        self.update_bounds();
+
+        Ok(vertex)
+    }
+
+    pub fn split_path(&mut self, path: usize, index: usize) -> Result<(), HersheyGlyphEditError> {
+        self.check_vertex(path, index)?;
+
+        if index == 0 || index == self.paths[path].len() - 1 {
+            return Err(HersheyGlyphEditError::OutOfRange(format!(
+                "Cannot split path {} at its end vertex {}",
+                path, index
+            )));
+        }
+
+        let tail = self.paths[path][index..].to_vec();
+
+        self.paths[path].truncate(index + 1);
+        self.paths.insert(path + 1, tail);
+
+        Ok(())
+    }
+
+    pub fn merge_paths(
+        &mut self,
+        first: usize,
+        second: usize,
+    ) -> Result<(), HersheyGlyphEditError> {
+        self.check_path(first)?;
+        self.check_path(second)?;
+
+        if first == second {
+            return Err(HersheyGlyphEditError::OutOfRange(format!(
+                "Cannot merge path {} with itself",
+                first
+            )));
+        }
+
+        let mut tail = self.paths[second].clone();
+
+        if self.paths[first].last() == tail.first() {
+            tail.remove(0);
+        }
+
+        self.paths[first].extend(tail);
+        self.paths.remove(second);
+
+        Ok(())
+    }
+
+    pub fn translate_path(
+        &mut self,
+        path: usize,
+        dx: i32,
+        dy: i32,
+    ) -> Result<(), HersheyGlyphEditError> {
+        self.check_path(path)?;
+
+        for edge in &mut self.paths[path] {
+            edge.x += dx;
+            edge.y += dy;
+        }
+
+        self.update_bounds();
+
+        Ok(())
+    }
+
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        for edge in self.paths.iter_mut().flatten() {
+            edge.x += dx;
+            edge.y += dy;
+        }
+
+        self.left += dx;
+        self.right += dx;
+        self.update_bounds();
+    }
+
+    pub fn set_bearings(&mut self, left: i32, right: i32) {
+        self.left = left;
+        self.right = right;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph() -> HersheyGlyph {
+        HersheyGlyph::new(
+            -5,
+            5,
+            vec![vec![
+                Edge { x: 0, y: -10 },
+                Edge { x: 0, y: 0 },
+                Edge { x: 0, y: 10 },
+            ]],
+        )
+    }
+
+    #[test]
+    fn move_vertex_updates_bounds() {
+        let mut glyph = glyph();
+
+        glyph.move_vertex(0, 2, Edge { x: 0, y: 12 }).unwrap();
+
+        assert_eq!(glyph.bottom, 12);
+    }
+
+    #[test]
+    fn insert_and_delete_vertex_work() {
+        let mut glyph = glyph();
+
+        glyph.insert_vertex(1, 0, Edge { x: 3, y: -20 }).unwrap();
+        assert_eq!(glyph.top, -20);
+
+        assert_eq!(glyph.delete_vertex(1, 0).unwrap(), Edge { x: 3, y: -20 });
+        assert_eq!(glyph.paths.len(), 1);
+        assert_eq!(glyph.top, -10);
+    }
+
+    #[test]
+    fn split_and_merge_paths_work() {
+        let mut glyph = glyph();
+        let original = glyph.paths.clone();
+
+        glyph.split_path(0, 1).unwrap();
+        assert_eq!(glyph.paths.len(), 2);
+        assert_eq!(glyph.paths[1][0], Edge { x: 0, y: 0 });
+
+        glyph.merge_paths(0, 1).unwrap();
+        assert_eq!(glyph.paths, original);
+    }
+
+    #[test]
+    fn translate_works() {
+        let mut glyph = glyph();
+
+        glyph.translate(2, 3);
+
+        assert_eq!(
+            (glyph.left, glyph.right, glyph.top, glyph.bottom),
+            (-3, 7, -7, 13)
+        );
+    }
+
+    #[test]
+    fn edits_return_error_if_out_of_range() {
+        let mut glyph = glyph();
+
+        assert!(matches!(
+            glyph.move_vertex(0, 3, Edge { x: 0, y: 0 }),
+            Err(HersheyGlyphEditError::OutOfRange(_))
+        ));
+        assert!(glyph.split_path(0, 0).is_err());
+        assert!(glyph.merge_paths(0, 0).is_err());
+        assert!(glyph.translate_path(1, 0, 0).is_err());
+    }
+}
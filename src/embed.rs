@@ -0,0 +1,176 @@
+use crate::mapping::CharMapping;
+use crate::{Edge, HersheyFont, HersheyFontNewError, HersheyGlyph};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StaticGlyph {
+    pub number: u32,
+    pub left: i32,
+    pub right: i32,
+    pub paths: &'static [&'static [(i32, i32)]],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StaticFont {
+    pub glyphs: &'static [StaticGlyph],
+    pub mapping: &'static [(char, usize)],
+}
+
+impl StaticGlyph {
+    pub fn to_glyph(&self) -> HersheyGlyph {
+        let mut glyph = HersheyGlyph::new(
+            self.left,
+            self.right,
+            self.paths
+                .iter()
+                .map(|path| path.iter().map(|(x, y)| Edge { x: *x, y: *y }).collect())
+                .collect(),
+        );
+
+        glyph.number = self.number;
+        glyph
+    }
+}
+
+impl StaticFont {
+    fn index(&self, glyph: char) -> Option<usize> {
+        if self.mapping.is_empty() {
+            return (glyph as usize).checked_sub(32);
+        }
+
+        self.mapping
+            .binary_search_by_key(&glyph, |(c, _)| *c)
+            .ok()
+            .map(|i| self.mapping[i].1)
+    }
+
+    pub fn get_glyph(&self, glyph: char) -> Option<&StaticGlyph> {
+        self.glyphs.get(self.index(glyph)?)
+    }
+
+    pub fn to_font(&self) -> HersheyFont {
+        let font = HersheyFont::from_parts(
+            Default::default(),
+            self.glyphs.iter().map(StaticGlyph::to_glyph).collect(),
+        );
+
+        if self.mapping.is_empty() {
+            font
+        } else {
+            font.with_mapping(CharMapping::Custom(self.mapping.iter().copied().collect()))
+        }
+    }
+}
+
+pub fn generate_static_font(data: &str, name: &str) -> Result<String, HersheyFontNewError> {
+    Ok(HersheyFont::new(data)?.to_static_font(name))
+}
+
+impl HersheyFont {
+    pub fn to_static_font(&self, name: &str) -> String {
+        let mut output = format!(
+            "pub static {}: hershey_parser::embed::StaticFont = hershey_parser::embed::StaticFont {{\n    glyphs: &[\n",
+            name
+        );
+
+        for glyph in &self.glyphs {
+            let paths = glyph
+                .paths
+                .iter()
+                .map(|path| {
+                    let points = path
+                        .iter()
+                        .map(|edge| format!("({}, {})", edge.x, edge.y))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    format!("&[{}]", points)
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            output.push_str(&format!(
+                "        hershey_parser::embed::StaticGlyph {{ number: {}, left: {}, right: {}, paths: &[{}] }},\n",
+                glyph.number, glyph.left, glyph.right, paths
+            ));
+        }
+
+        let mut mapping = match self.mapping {
+            CharMapping::AsciiOffset => Vec::new(),
+            _ => (0..self.glyphs.len())
+                .flat_map(|i| self.chars_for_glyph(i).into_iter().map(move |c| (c, i)))
+                .collect(),
+        };
+        mapping.sort_unstable();
+
+        let mapping = mapping
+            .iter()
+            .map(|(c, i)| format!("({:?}, {})", c, i))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        output.push_str(&format!("    ],\n    mapping: &[{}],\n}};\n", mapping));
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    static FONT: StaticFont = StaticFont {
+        glyphs: &[
+            StaticGlyph {
+                number: 699,
+                left: -8,
+                right: 8,
+                paths: &[],
+            },
+            StaticGlyph {
+                number: 720,
+                left: -11,
+                right: 11,
+                paths: &[&[(9, -16), (-9, 16)]],
+            },
+        ],
+        mapping: &[],
+    };
+
+    #[test]
+    fn generate_static_font_works() {
+        let source = generate_static_font("  699  1JZ\n  720  3G][BIb", "FONT").unwrap();
+
+        assert_eq!(
+            source,
+            "pub static FONT: hershey_parser::embed::StaticFont = hershey_parser::embed::StaticFont {\n    glyphs: &[\n        hershey_parser::embed::StaticGlyph { number: 699, left: -8, right: 8, paths: &[] },\n        hershey_parser::embed::StaticGlyph { number: 720, left: -11, right: 11, paths: &[&[(9, -16), (-9, 16)]] },\n    ],\n    mapping: &[],\n};\n"
+        );
+        assert!(generate_static_font("  720  3G]", "FONT").is_err());
+    }
+
+    #[test]
+    fn to_font_matches_parsed_font() {
+        let parsed = HersheyFont::new("  699  1JZ\n  720  3G][BIb").unwrap();
+
+        assert_eq!(FONT.to_font(), parsed);
+        assert_eq!(FONT.get_glyph('!').unwrap().number, 720);
+        assert!(FONT.get_glyph('\n').is_none());
+    }
+
+    #[test]
+    fn static_font_uses_mapping() {
+        static MAPPED: StaticFont = StaticFont {
+            glyphs: FONT.glyphs,
+            mapping: &[('A', 1), ('é', 0)],
+        };
+        let font = HersheyFont::new("  699  1JZ\n  720  3G][BIb")
+            .unwrap()
+            .with_mapping(CharMapping::Custom(HashMap::from([('é', 0), ('A', 1)])));
+
+        assert_eq!(MAPPED.get_glyph('é').unwrap().number, 699);
+        assert!(MAPPED.get_glyph(' ').is_none());
+        assert_eq!(MAPPED.to_font(), font);
+        assert!(font
+            .to_static_font("MAPPED")
+            .ends_with("    ],\n    mapping: &[('A', 1), ('é', 0)],\n};\n"));
+    }
+}
@@ -0,0 +1,238 @@
+use crate::gcode::format_number;
+use crate::layout::TextLayout;
+use crate::meta::comment_lines;
+use crate::units::Units;
+use crate::{Edge, HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EpsOptions {
+    pub scale: f64,
+    pub stroke_width: f64,
+    pub padding: f64,
+    pub flip_y: bool,
+    pub comments: Vec<String>,
+    pub units: Option<Units>,
+}
+
+const POINTS_PER_INCH: f64 = 72.0;
+
+impl Default for EpsOptions {
+    fn default() -> EpsOptions {
+        EpsOptions {
+            scale: 1.0,
+            stroke_width: 1.0,
+            padding: 0.0,
+            flip_y: true,
+            comments: Vec::new(),
+            units: None,
+        }
+    }
+}
+
+pub fn write_eps_points(paths: &[Vec<PointF>], options: &EpsOptions) -> String {
+    let scale = options.scale
+        * options
+            .units
+            .map_or(1.0, |units| units.factor(Units::Inches) * POINTS_PER_INCH);
+    let to_page = |point: &PointF| PointF {
+        x: point.x * scale,
+        y: point.y * scale * if options.flip_y { -1.0 } else { 1.0 },
+    };
+    let margin = options.padding + options.stroke_width / 2.0;
+
+    let (left, bottom, right, top) = paths
+        .iter()
+        .flatten()
+        .map(to_page)
+        .fold(None, |accum: Option<(f64, f64, f64, f64)>, point| {
+            Some(match accum {
+                Some((left, bottom, right, top)) => (
+                    left.min(point.x),
+                    bottom.min(point.y),
+                    right.max(point.x),
+                    top.max(point.y),
+                ),
+                None => (point.x, point.y, point.x, point.y),
+            })
+        })
+        .map_or((0.0, 0.0, 0.0, 0.0), |(left, bottom, right, top)| {
+            (left - margin, bottom - margin, right + margin, top + margin)
+        });
+
+    let mut lines = vec![
+        "%!PS-Adobe-3.0 EPSF-3.0".to_string(),
+        format!(
+            "%%BoundingBox: {} {} {} {}",
+            left.floor(),
+            bottom.floor(),
+            right.ceil(),
+            top.ceil()
+        ),
+        format!(
+            "%%HiResBoundingBox: {} {} {} {}",
+            format_number(left),
+            format_number(bottom),
+            format_number(right),
+            format_number(top)
+        ),
+    ];
+
+    lines.extend(
+        options
+            .comments
+            .iter()
+            .flat_map(|comment| comment_lines(comment))
+            .map(|line| format!("% {}", line)),
+    );
+    lines.push("%%EndComments".to_string());
+    lines.push(format!(
+        "{} setlinewidth",
+        format_number(options.stroke_width)
+    ));
+    lines.push("1 setlinecap".to_string());
+    lines.push("1 setlinejoin".to_string());
+
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        let start = to_page(&path[0]);
+        let mut commands = vec![format!(
+            "newpath {} {} moveto",
+            format_number(start.x),
+            format_number(start.y)
+        )];
+        let rest = if path.len() == 1 { path } else { &path[1..] };
+
+        commands.extend(rest.iter().map(|point| {
+            let point = to_page(point);
+
+            format!(
+                "{} {} lineto",
+                format_number(point.x),
+                format_number(point.y)
+            )
+        }));
+        commands.push("stroke".to_string());
+        lines.push(commands.join(" "));
+    }
+
+    lines.push("showpage".to_string());
+    lines.push("%%EOF".to_string());
+
+    lines.join("\n") + "\n"
+}
+
+pub fn write_eps(paths: &[Vec<Edge>], options: &EpsOptions) -> String {
+    let points = paths
+        .iter()
+        .map(|path| path.iter().copied().map(PointF::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    write_eps_points(&points, options)
+}
+
+impl HersheyGlyph {
+    pub fn to_eps(&self, options: &EpsOptions) -> String {
+        write_eps(&self.paths, options)
+    }
+}
+
+impl TextLayout {
+    pub fn to_eps(&self, options: &EpsOptions) -> String {
+        write_eps(&self.paths(), options)
+    }
+}
+
+impl HersheyFont {
+    pub fn text_to_eps(&self, text: &str, options: &EpsOptions) -> String {
+        let mut options = options.clone();
+
+        if let Some(meta) = self.meta() {
+            options.comments.extend(meta.lines());
+        }
+
+        options.flip_y = options.flip_y != self.y_up();
+        self.layout_text(text).to_eps(&options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+
+    #[test]
+    fn write_eps_works() {
+        let paths = vec![
+            vec![Edge { x: 0, y: -10 }, Edge { x: 5, y: 10 }],
+            vec![Edge { x: 2, y: 0 }],
+        ];
+        let eps = write_eps(
+            &paths,
+            &EpsOptions {
+                scale: 0.5,
+                stroke_width: 0.5,
+                comments: vec!["Name: test".to_string()],
+                ..EpsOptions::default()
+            },
+        );
+
+        assert_eq!(
+            eps,
+            "%!PS-Adobe-3.0 EPSF-3.0\n%%BoundingBox: -1 -6 3 6\n%%HiResBoundingBox: -0.25 -5.25 2.75 5.25\n% Name: test\n%%EndComments\n0.5 setlinewidth\n1 setlinecap\n1 setlinejoin\nnewpath 0 5 moveto 2.5 -5 lineto stroke\nnewpath 1 0 moveto 1 0 lineto stroke\nshowpage\n%%EOF\n"
+        );
+    }
+
+    #[test]
+    fn write_eps_converts_units_to_points() {
+        let paths = vec![vec![Edge { x: 0, y: 0 }, Edge { x: 2, y: -1 }]];
+        let eps = write_eps(
+            &paths,
+            &EpsOptions {
+                scale: 0.5,
+                units: Some(Units::Inches),
+                ..EpsOptions::default()
+            },
+        );
+
+        assert!(eps.contains("newpath 0 0 moveto 72 36 lineto stroke\n"));
+    }
+
+    #[test]
+    fn write_eps_keeps_comment_lines_commented() {
+        let eps = write_eps(
+            &[],
+            &EpsOptions {
+                comments: vec!["Name: x\nshowpage".to_string()],
+                ..EpsOptions::default()
+            },
+        );
+
+        assert!(eps.contains("% Name: x\n% showpage\n%%EndComments\n"));
+    }
+
+    #[test]
+    fn text_to_eps_includes_metadata() {
+        let font = HersheyFont::new("name: futural\n  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let eps = font.text_to_eps("!!", &EpsOptions::default());
+
+        assert!(eps.contains("% Name: futural\n%%EndComments\n"));
+        assert!(eps.contains("%%BoundingBox: 4 -11 16 11\n"));
+        assert_eq!(eps.matches(" stroke\n").count(), 2);
+    }
+
+    #[test]
+    fn write_eps_handles_empty_input() {
+        assert!(write_eps(&[], &EpsOptions::default()).contains("%%BoundingBox: 0 0 0 0\n"));
+    }
+
+    #[test]
+    fn text_to_eps_follows_coordinate_system() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHRR").unwrap();
+        let mut y_up = font.clone();
+        y_up.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            y_up.text_to_eps("!\n!", &EpsOptions::default()),
+            font.text_to_eps("!\n!", &EpsOptions::default())
+        );
+    }
+}
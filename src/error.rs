@@ -0,0 +1,93 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseErrorKind {
+    TruncatedRecord,
+    NonAsciiCharacter,
+    InvalidUtf8,
+    InvalidVertexCount(String),
+    VertexCountMismatch { declared: usize, found: usize },
+    InvalidCoordinate(char),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::TruncatedRecord => write!(f, "record is too short"),
+            ParseErrorKind::NonAsciiCharacter => write!(f, "non-ASCII character"),
+            ParseErrorKind::InvalidUtf8 => write!(f, "data is not valid UTF-8"),
+            ParseErrorKind::InvalidVertexCount(count) => {
+                write!(f, "invalid vertex count {:?}", count)
+            }
+            ParseErrorKind::VertexCountMismatch { declared, found } => {
+                write!(f, "declared {} vertices but found {}", declared, found)
+            }
+            ParseErrorKind::InvalidCoordinate(c) => {
+                write!(f, "invalid coordinate character {:?}", c)
+            }
+        }
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: Option<usize>,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, column: Option<usize>) -> ParseError {
+        ParseError {
+            line: 0,
+            column,
+            kind,
+        }
+    }
+
+    pub(crate) fn at_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(
+                f,
+                "Error parsing line {}, column {}: {}",
+                self.line, column, self.kind
+            ),
+            None => write!(f, "Error parsing line {}: {}", self.line, self.kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_works() {
+        let error = ParseError::new(
+            ParseErrorKind::VertexCountMismatch {
+                declared: 4,
+                found: 3,
+            },
+            None,
+        )
+        .at_line(2);
+
+        assert_eq!(
+            error.to_string(),
+            "Error parsing line 2: declared 4 vertices but found 3"
+        );
+        assert_eq!(
+            ParseError::new(ParseErrorKind::InvalidCoordinate('\t'), Some(12))
+                .at_line(1)
+                .to_string(),
+            "Error parsing line 1, column 12: invalid coordinate character '\\t'"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::area::signed_area;
+use crate::{Edge, HersheyGlyph};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon {
+    pub outer: Vec<Edge>,
+    pub holes: Vec<Vec<Edge>>,
+}
+
+pub(crate) fn is_closed(path: &[Edge]) -> bool {
+    path.len() >= 4 && path.first() == path.last()
+}
+
+pub(crate) fn ring_contains(ring: &[Edge], x: f64, y: f64) -> bool {
+    let mut inside = false;
+
+    for pair in ring.windows(2) {
+        let (x0, y0) = (pair[0].x as f64, pair[0].y as f64);
+        let (x1, y1) = (pair[1].x as f64, pair[1].y as f64);
+
+        if (y0 > y) != (y1 > y) && x < x0 + (y - y0) * (x1 - x0) / (y1 - y0) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+impl Polygon {
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        ring_contains(&self.outer, x, y) && !self.holes.iter().any(|hole| ring_contains(hole, x, y))
+    }
+}
+
+/// Builds fillable polygons from the closed paths only; open centerline
+/// strokes have no interior and are left out rather than expanded.
+pub fn fill_polygons(paths: &[Vec<Edge>]) -> Vec<Polygon> {
+    let rings = paths
+        .iter()
+        .filter(|path| is_closed(path))
+        .collect::<Vec<_>>();
+
+    let parents = rings
+        .iter()
+        .enumerate()
+        .map(|(i, ring)| {
+            let probe = (ring[0].x as f64 + 0.001, ring[0].y as f64 + 0.0007);
+
+            rings
+                .iter()
+                .enumerate()
+                .filter(|(j, other)| *j != i && ring_contains(other, probe.0, probe.1))
+                .map(|(j, _)| j)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut polygons = Vec::new();
+    let mut polygon_index = vec![None; rings.len()];
+
+    for (i, enclosing) in parents.iter().enumerate() {
+        if enclosing.len() % 2 == 0 {
+            polygon_index[i] = Some(polygons.len());
+            polygons.push(Polygon {
+                outer: rings[i].to_vec(),
+                holes: Vec::new(),
+            });
+        }
+    }
+
+    for (i, enclosing) in parents.iter().enumerate() {
+        if enclosing.len() % 2 == 1 {
+            let parent = enclosing
+                .iter()
+                .filter(|j| parents[**j].len() == enclosing.len() - 1)
+                .min_by(|a, b| {
+                    signed_area(rings[**a])
+                        .abs()
+                        .total_cmp(&signed_area(rings[**b]).abs())
+                });
+
+            if let Some(index) = parent.and_then(|j| polygon_index[*j]) {
+                polygons[index].holes.push(rings[i].to_vec());
+            }
+        }
+    }
+
+    polygons
+}
+
+impl HersheyGlyph {
+    /// See [`fill_polygons`]: open strokes are not included.
+    pub fn fill_polygons(&self) -> Vec<Polygon> {
+        fill_polygons(&self.paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HersheyFont;
+
+    fn glyph_paths(data: &str) -> Vec<Vec<Edge>> {
+        HersheyFont::new(data)
+            .unwrap()
+            .get_glyph(' ')
+            .unwrap()
+            .paths
+            .clone()
+    }
+
+    #[test]
+    fn fill_polygons_assigns_holes() {
+        let paths = glyph_paths(r"  714 21JZHH\H\\H\HH RMMWMWWMWMM RPPTPTTPTPP RfRpR");
+        let polygons = fill_polygons(&paths);
+
+        assert_eq!(
+            polygons,
+            vec![
+                Polygon {
+                    outer: paths[0].clone(),
+                    holes: vec![paths[1].clone()],
+                },
+                Polygon {
+                    outer: paths[2].clone(),
+                    holes: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fill_polygons_ignores_open_strokes() {
+        let paths = glyph_paths(r"  714  9JZHH\H\\H\HH RMMWW");
+
+        assert_eq!(fill_polygons(&paths).len(), 1);
+        assert!(fill_polygons(&paths[1..]).is_empty());
+    }
+
+    #[test]
+    fn contains_uses_even_odd_rule() {
+        let mut paths = glyph_paths(r"  714 12JZHH\H\\H\HH RMMWMWWMWMM");
+        let polygon = Polygon {
+            holes: paths.split_off(1),
+            outer: paths.remove(0),
+        };
+
+        assert!(polygon.contains(7.0, 0.0));
+        assert!(!polygon.contains(0.0, 0.0));
+        assert!(!polygon.contains(11.0, 0.0));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::canonical::Fnv1a;
+use crate::HersheyFont;
+
+impl HersheyFont {
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+
+        hasher.write_u64(self.glyphs.len() as u64);
+
+        for (i, glyph) in self.glyphs.iter().enumerate() {
+            let chars = self.chars_for_glyph(i);
+
+            hasher.write_u64(chars.len() as u64);
+
+            for c in chars {
+                hasher.write_u64(c as u64);
+            }
+
+            hasher.write_u64(glyph.number as u64);
+            hasher.write_i32(glyph.left);
+            hasher.write_i32(glyph.right);
+            hasher.write_u64(glyph.paths.len() as u64);
+
+            for path in &glyph.paths {
+                hasher.write_u64(path.len() as u64);
+
+                for edge in path {
+                    hasher.write_i32(edge.x);
+                    hasher.write_i32(edge.y);
+                }
+            }
+        }
+
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_ignores_metadata_and_comments() {
+        let first = HersheyFont::new("  720  3JZRHR\\").unwrap();
+        let second = HersheyFont::new("name: test\n# comment\n  720  3JZRHR\\").unwrap();
+
+        assert_eq!(first.fingerprint(), second.fingerprint());
+    }
+
+    #[test]
+    fn fingerprint_detects_changes() {
+        let font = HersheyFont::new("  720  3JZRHR\\").unwrap();
+
+        for data in [
+            "  720  3KZRHR\\",
+            "  720  3JZRHR[",
+            "  720  1JZ\n  720  3JZRHR\\",
+            "  721  3JZRHR\\",
+        ] {
+            assert_ne!(
+                font.fingerprint(),
+                HersheyFont::new(data).unwrap().fingerprint()
+            );
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{metadata_entry, Edge};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedGlyph<const N: usize> {
+    pub left: i32,
+    pub right: i32,
+    vertices: [Option<Edge>; N],
+    len: usize,
+}
+
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq)]
+pub enum FixedDecodeError {
+    #[error("Invalid glyph data")]
+    InvalidData,
+    #[error("Glyph has more vertices than the fixed capacity")]
+    CapacityExceeded,
+    #[error("Glyph not found in font")]
+    GlyphNotFound,
+}
+
+fn byte_to_int(byte: u8) -> i32 {
+    byte as i32 - b'R' as i32
+}
+
+impl<const N: usize> FixedGlyph<N> {
+    pub fn decode(line: &str) -> Result<FixedGlyph<N>, FixedDecodeError> {
+        let bytes = line.as_bytes();
+
+        if bytes.len() < 10 || !line.is_ascii() {
+            return Err(FixedDecodeError::InvalidData);
+        }
+
+        let contents = &bytes[5..];
+        let count = core::str::from_utf8(&contents[..3])
+            .ok()
+            .and_then(|count| count.trim().parse::<usize>().ok())
+            .filter(|count| *count > 0)
+            .ok_or(FixedDecodeError::InvalidData)?;
+        let pairs = count - 1;
+
+        if contents.len() != 5 + pairs * 2 {
+            return Err(FixedDecodeError::InvalidData);
+        }
+
+        if pairs > N {
+            return Err(FixedDecodeError::CapacityExceeded);
+        }
+
+        let mut glyph = FixedGlyph {
+            left: byte_to_int(contents[3]),
+            right: byte_to_int(contents[4]),
+            vertices: [None; N],
+            len: pairs,
+        };
+
+        for i in 0..pairs {
+            let pair = &contents[5 + i * 2..7 + i * 2];
+
+            if pair != b" R" {
+                glyph.vertices[i] = Some(Edge {
+                    x: byte_to_int(pair[0]),
+                    y: byte_to_int(pair[1]),
+                });
+            }
+        }
+
+        Ok(glyph)
+    }
+
+    pub fn vertices(&self) -> &[Option<Edge>] {
+        &self.vertices[..self.len]
+    }
+
+    pub fn for_each_segment(&self, mut f: impl FnMut(Edge, Edge)) {
+        for pair in self.vertices().windows(2) {
+            if let (Some(a), Some(b)) = (pair[0], pair[1]) {
+                f(a, b);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedFont<'a> {
+    data: &'a str,
+}
+
+impl<'a> FixedFont<'a> {
+    pub const fn new(data: &'a str) -> FixedFont<'a> {
+        FixedFont { data }
+    }
+
+    pub fn get_glyph<const N: usize>(
+        &self,
+        glyph: char,
+    ) -> Result<FixedGlyph<N>, FixedDecodeError> {
+        let index = (glyph as usize)
+            .checked_sub(32)
+            .ok_or(FixedDecodeError::GlyphNotFound)?;
+        let line = self
+            .data
+            .split('\n')
+            .map(|line| line.trim_end_matches('\r'))
+            .filter(|line| !line.is_empty() && !line.trim_start().starts_with('#'))
+            .skip_while(|line| metadata_entry(line).is_some())
+            .nth(index)
+            .ok_or(FixedDecodeError::GlyphNotFound)?;
+
+        FixedGlyph::decode(line)
+    }
+
+    pub fn layout_line<const N: usize>(
+        &self,
+        text: &str,
+        mut f: impl FnMut(Edge, Edge),
+    ) -> Result<i32, FixedDecodeError> {
+        let mut pen_x = 0;
+
+        for c in text.chars() {
+            let glyph = self.get_glyph::<N>(c)?;
+            let offset = pen_x - glyph.left;
+
+            glyph.for_each_segment(|a, b| {
+                f(
+                    Edge {
+                        x: a.x + offset,
+                        y: a.y,
+                    },
+                    Edge {
+                        x: b.x + offset,
+                        y: b.y,
+                    },
+                )
+            });
+
+            pen_x += glyph.right - glyph.left;
+        }
+
+        Ok(pen_x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static FONT: FixedFont = FixedFont::new("  720  1JZ\n  720  6G]RBRK RRRRW");
+
+    #[test]
+    fn decode_works() {
+        let glyph = FixedGlyph::<8>::decode("  720  6G]RBRK RRRRW").unwrap();
+
+        assert_eq!(glyph.left, -11);
+        assert_eq!(
+            glyph.vertices(),
+            &[
+                Some(Edge { x: 0, y: -16 }),
+                Some(Edge { x: 0, y: -7 }),
+                None,
+                Some(Edge { x: 0, y: 0 }),
+                Some(Edge { x: 0, y: 5 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_returns_error_if_capacity_is_exceeded() {
+        assert_eq!(
+            FixedGlyph::<4>::decode("  720  6G]RBRK RRRRW"),
+            Err(FixedDecodeError::CapacityExceeded)
+        );
+        assert_eq!(
+            FixedGlyph::<4>::decode("  720  9G]"),
+            Err(FixedDecodeError::InvalidData)
+        );
+    }
+
+    #[test]
+    fn layout_line_works() {
+        let mut segments = [(Edge { x: 0, y: 0 }, Edge { x: 0, y: 0 }); 4];
+        let mut count = 0;
+
+        let width = FONT
+            .layout_line::<8>(" !", |a, b| {
+                segments[count] = (a, b);
+                count += 1;
+            })
+            .unwrap();
+
+        assert_eq!(width, 38);
+        assert_eq!(count, 2);
+        assert_eq!(segments[0], (Edge { x: 27, y: -16 }, Edge { x: 27, y: -7 }));
+    }
+
+    #[test]
+    fn get_glyph_skips_metadata_headers() {
+        let font = FixedFont::new(
+            "name: test\n# comment\nsource: test.jhf\n  720  1JZ\n  720  6G]RBRK RRRRW",
+        );
+
+        assert_eq!(font.get_glyph::<8>(' ').unwrap().len, 0);
+        assert_eq!(font.get_glyph::<8>('!').unwrap().left, -11);
+    }
+
+    #[test]
+    fn get_glyph_returns_error_if_glyph_is_not_found() {
+        assert_eq!(
+            FONT.get_glyph::<8>('A'),
+            Err(FixedDecodeError::GlyphNotFound)
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::layout::TextLayout;
+use crate::{Edge, HersheyFont, HersheyGlyph};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlatPaths {
+    pub coordinates: Vec<f32>,
+    pub path_starts: Vec<u32>,
+}
+
+impl FlatPaths {
+    pub fn from_paths(paths: &[Vec<Edge>]) -> FlatPaths {
+        let mut flat = FlatPaths::default();
+
+        for path in paths {
+            flat.path_starts.push((flat.coordinates.len() / 2) as u32);
+            flat.coordinates
+                .extend(path.iter().flat_map(|edge| [edge.x as f32, edge.y as f32]));
+        }
+
+        flat
+    }
+
+    pub fn path_count(&self) -> usize {
+        self.path_starts.len()
+    }
+
+    pub fn path(&self, index: usize) -> Option<&[f32]> {
+        let start = *self.path_starts.get(index)? as usize * 2;
+        let end = self
+            .path_starts
+            .get(index + 1)
+            .map_or(self.coordinates.len(), |end| *end as usize * 2);
+
+        self.coordinates.get(start..end)
+    }
+}
+
+impl HersheyGlyph {
+    pub fn to_flat(&self) -> FlatPaths {
+        FlatPaths::from_paths(&self.paths)
+    }
+}
+
+impl TextLayout {
+    pub fn to_flat(&self) -> FlatPaths {
+        FlatPaths::from_paths(&self.paths())
+    }
+}
+
+impl HersheyFont {
+    pub fn glyph_paths_flat(&self, c: char) -> Option<FlatPaths> {
+        self.get_glyph_checked(c).map(HersheyGlyph::to_flat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_flat_works() {
+        let font = HersheyFont::new("  714  6MWRHR\\ RPRTR").unwrap();
+        let flat = font.glyph_paths_flat(' ').unwrap();
+
+        assert_eq!(
+            flat.coordinates,
+            vec![0.0, -10.0, 0.0, 10.0, -2.0, 0.0, 2.0, 0.0]
+        );
+        assert_eq!(flat.path_starts, vec![0, 2]);
+        assert_eq!(flat.path_count(), 2);
+        assert_eq!(flat.path(1), Some(&[-2.0, 0.0, 2.0, 0.0][..]));
+        assert_eq!(flat.path(2), None);
+        assert!(font.glyph_paths_flat('!').is_none());
+    }
+
+    #[test]
+    fn text_layout_to_flat_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let flat = font.layout_text("!!").to_flat();
+
+        assert_eq!(flat.path_starts, vec![0, 2]);
+        assert_eq!(&flat.coordinates[4..6], &[15.0, -10.0]);
+    }
+}
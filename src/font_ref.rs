@@ -0,0 +1,170 @@
+use crate::error::ParseError;
+use crate::index::NumberIndex;
+use crate::mapping::CharMapping;
+use crate::meta::FontMeta;
+use crate::{
+    record_to_hershey_glyph, split_records, HersheyFont, HersheyFontNewError, HersheyGlyph,
+    ParseOptions, RecordLine,
+};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HersheyFontRef<'a> {
+    pub metadata: BTreeMap<String, String>,
+    pub mapping: CharMapping,
+    options: ParseOptions,
+    lines: Vec<RecordLine<'a>>,
+    numbers: NumberIndex,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyFontRefGetGlyphError {
+    #[error("{0}")]
+    GlyphNotFound(String),
+    #[error("{0}")]
+    ParseError(#[from] ParseError),
+}
+
+impl<'a> HersheyFontRef<'a> {
+    pub fn new(data: &'a str) -> HersheyFontRef<'a> {
+        HersheyFontRef::new_with_options(data, &ParseOptions::default())
+    }
+
+    pub fn new_with_options(data: &'a str, options: &ParseOptions) -> HersheyFontRef<'a> {
+        let (metadata, lines) = split_records(data);
+
+        HersheyFontRef {
+            metadata,
+            mapping: CharMapping::default(),
+            options: *options,
+            numbers: NumberIndex::new(lines.iter().map(|record| {
+                record
+                    .text
+                    .get(..5)
+                    .and_then(|number| number.trim().parse().ok())
+                    .unwrap_or(0)
+            })),
+            lines,
+        }
+    }
+
+    pub fn with_mapping(mut self, mapping: CharMapping) -> HersheyFontRef<'a> {
+        self.mapping = mapping;
+        self
+    }
+
+    pub(crate) fn index(&self, glyph: char) -> Option<usize> {
+        self.mapping
+            .index(&self.numbers, glyph)
+            .filter(|index| *index < self.lines.len())
+    }
+
+    pub fn meta(&self) -> Option<FontMeta> {
+        FontMeta::from_metadata(&self.metadata)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn source_line(&self, glyph: char) -> Option<&str> {
+        self.lines
+            .get(self.index(glyph)?)
+            .map(|record| record.text.as_ref())
+    }
+
+    pub fn get_glyph(&self, glyph: char) -> Result<HersheyGlyph, HersheyFontRefGetGlyphError> {
+        let record = self
+            .index(glyph)
+            .and_then(|index| self.lines.get(index))
+            .ok_or(HersheyFontRefGetGlyphError::GlyphNotFound(format!(
+                "Glyph {} not found in font",
+                glyph
+            )))?;
+
+        Ok(record_to_hershey_glyph(record, &self.options)?)
+    }
+
+    pub fn to_font(&self) -> Result<HersheyFont, HersheyFontNewError> {
+        let glyphs = self
+            .lines
+            .iter()
+            .map(|record| record_to_hershey_glyph(record, &self.options))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        Ok(HersheyFont::from_parts(self.metadata.clone(), glyphs)
+            .with_mapping(self.mapping.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hmp::HersheyMap;
+
+    #[test]
+    fn get_glyph_works() {
+        let data = "name: test\n  720  3G][BIb\n  720  1JZ".to_string();
+        let font = HersheyFontRef::new(&data);
+
+        assert_eq!(font.len(), 2);
+        assert_eq!(font.source_line('!'), Some("  720  1JZ"));
+        assert_eq!(
+            font.get_glyph(' ').unwrap(),
+            HersheyFont::new(&data)
+                .unwrap()
+                .get_glyph(' ')
+                .unwrap()
+                .clone()
+        );
+    }
+
+    #[test]
+    fn get_glyph_returns_error_if_glyph_is_not_found() {
+        let font = HersheyFontRef::new("  720  3G][BIb");
+
+        assert!(matches!(
+            font.get_glyph('A'),
+            Err(HersheyFontRefGetGlyphError::GlyphNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn get_glyph_reports_parse_errors_on_access() {
+        let font = HersheyFontRef::new("  720  3G][BIb\n  720  9G]");
+
+        assert!(font.get_glyph(' ').is_ok());
+        assert!(matches!(
+            font.get_glyph('!'),
+            Err(HersheyFontRefGetGlyphError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn get_glyph_uses_mapping() {
+        let font = HersheyFontRef::new("  699  1JZ\n  720  3G][BIb").with_mapping(
+            CharMapping::HersheyMapFile(HersheyMap {
+                numbers: vec![720, 699],
+            }),
+        );
+
+        assert_eq!(font.get_glyph(' ').unwrap().number, 720);
+        assert_eq!(font.source_line('!'), Some("  699  1JZ"));
+        assert_eq!(font.to_font().unwrap().get_glyph(' ').unwrap().number, 720);
+        assert!(font.get_glyph('"').is_err());
+    }
+
+    #[test]
+    fn to_font_works() {
+        let font = HersheyFontRef::new("name: test\n  720  3G][BIb");
+
+        assert_eq!(
+            font.to_font().unwrap(),
+            HersheyFont::new("name: test\n  720  3G][BIb").unwrap()
+        );
+    }
+}
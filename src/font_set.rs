@@ -0,0 +1,153 @@
+use crate::layout::{LayoutOptions, TextLayout};
+use crate::{HersheyFont, HersheyFontGetGlyphError, HersheyGlyph};
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Debug)]
+struct FontSetEntry {
+    font: HersheyFont,
+    ranges: Vec<RangeInclusive<char>>,
+}
+
+impl FontSetEntry {
+    fn covers(&self, c: char) -> bool {
+        (self.ranges.is_empty() || self.ranges.iter().any(|range| range.contains(&c)))
+            && self.font.get_glyph_checked(c).is_some()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FontSet {
+    entries: Vec<FontSetEntry>,
+}
+
+impl FontSet {
+    pub fn new() -> FontSet {
+        FontSet::default()
+    }
+
+    pub fn with_font(
+        mut self,
+        font: HersheyFont,
+        ranges: impl IntoIterator<Item = RangeInclusive<char>>,
+    ) -> FontSet {
+        self.entries.push(FontSetEntry {
+            font,
+            ranges: ranges.into_iter().collect(),
+        });
+        self
+    }
+
+    pub fn with_fallback(self, font: HersheyFont) -> FontSet {
+        self.with_font(font, [])
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn font_for(&self, c: char) -> Option<&HersheyFont> {
+        self.entries
+            .iter()
+            .find(|entry| entry.covers(c))
+            .map(|entry| &entry.font)
+    }
+
+    pub fn get_glyph(&self, c: char) -> Result<&HersheyGlyph, HersheyFontGetGlyphError> {
+        self.font_for(c)
+            .and_then(|font| font.get_glyph_checked(c))
+            .ok_or(HersheyFontGetGlyphError::GlyphNotFound(format!(
+                "Glyph {} not found in font set",
+                c
+            )))
+    }
+
+    pub fn layout_text(&self, text: &str) -> TextLayout {
+        self.layout_text_with_options(text, &LayoutOptions::default())
+    }
+
+    pub fn layout_text_with_options(&self, text: &str, options: &LayoutOptions) -> TextLayout {
+        match self.entries.first() {
+            Some(primary) => {
+                primary
+                    .font
+                    .layout_text_with_fonts(&|c| self.font_for(c), text, options)
+            }
+            None => TextLayout::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Alignment;
+    use crate::mapping::CharMapping;
+    use std::collections::HashMap;
+
+    fn latin() -> HersheyFont {
+        HersheyFont::new("  699  1JZ\n  714  3MWRHR\\").unwrap()
+    }
+
+    fn greek() -> HersheyFont {
+        HersheyFont::new("  527  3JZRHR\\")
+            .unwrap()
+            .with_mapping(CharMapping::Custom(HashMap::from([('Ω', 0), ('!', 0)])))
+    }
+
+    #[test]
+    fn font_set_resolves_by_range_and_fallback_order() {
+        let set = FontSet::new()
+            .with_font(greek(), ['\u{370}'..='\u{3ff}'])
+            .with_fallback(latin());
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get_glyph('Ω').unwrap().number, 527);
+        assert_eq!(set.get_glyph('!').unwrap().number, 714);
+        assert!(set.get_glyph('α').is_err());
+        assert!(FontSet::new().font_for('a').is_none());
+    }
+
+    #[test]
+    fn font_set_layout_text_mixes_fonts() {
+        let set = FontSet::new()
+            .with_fallback(latin())
+            .with_font(greek(), ['Ω'..='Ω']);
+        let layout = set.layout_text("! Ω\n!");
+
+        assert_eq!(
+            layout.positions(),
+            vec![('!', 0, 0), (' ', 10, 0), ('Ω', 26, 0), ('!', 0, 20)]
+        );
+        assert_eq!(layout.lines[0].width, 42);
+        assert_eq!(layout.width, 42);
+        assert_eq!(layout.glyphs[2].paths[0][0].x, 34);
+    }
+
+    #[test]
+    fn font_set_layout_text_with_options_uses_shared_layout() {
+        let tall = HersheyFont::new("  527  3JZRDR`")
+            .unwrap()
+            .with_mapping(CharMapping::Custom(HashMap::from([('Ω', 0)])));
+        let set = FontSet::new()
+            .with_fallback(latin())
+            .with_font(tall, ['Ω'..='Ω']);
+        let layout = set.layout_text_with_options(
+            "!Ω\n!",
+            &LayoutOptions {
+                tracking: 2,
+                alignment: Alignment::Right,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(
+            layout.positions(),
+            vec![('!', 0, 0), ('Ω', 12, 0), ('!', 18, 28)]
+        );
+        assert_eq!((layout.width, layout.height), (28, 56));
+    }
+}
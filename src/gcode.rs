@@ -0,0 +1,435 @@
+use crate::layout::TextLayout;
+use crate::meta::comment_lines;
+use crate::style::StyledPaths;
+use crate::units::Units;
+use crate::{Edge, HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Limits {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MachineProfile {
+    pub name: String,
+    pub header: String,
+    pub footer: String,
+    pub pen_up: String,
+    pub pen_down: String,
+    pub feed_rate: f64,
+    pub limits: Option<Limits>,
+}
+
+impl MachineProfile {
+    pub fn grbl_pen_plotter() -> MachineProfile {
+        MachineProfile {
+            name: "grbl pen plotter".to_string(),
+            header: "G21\nG90\nG0 Z5".to_string(),
+            footer: "G0 Z5\nG0 X0 Y0".to_string(),
+            pen_up: "G0 Z5".to_string(),
+            pen_down: "G1 Z0 F{feed_rate}".to_string(),
+            feed_rate: 1000.0,
+            limits: None,
+        }
+    }
+
+    pub fn laser_m3() -> MachineProfile {
+        MachineProfile {
+            name: "laser (M3 constant power)".to_string(),
+            header: "G21\nG90\nM5".to_string(),
+            footer: "M5\nG0 X0 Y0".to_string(),
+            pen_up: "M5".to_string(),
+            pen_down: "M3 S1000".to_string(),
+            feed_rate: 1500.0,
+            limits: None,
+        }
+    }
+
+    pub fn laser_m4() -> MachineProfile {
+        MachineProfile {
+            name: "laser (M4 dynamic power)".to_string(),
+            pen_down: "M4 S1000".to_string(),
+            ..MachineProfile::laser_m3()
+        }
+    }
+
+    pub fn cnc_spindle() -> MachineProfile {
+        MachineProfile {
+            name: "CNC spindle".to_string(),
+            header: "G21\nG90\nG0 Z2\nM3 S10000".to_string(),
+            footer: "G0 Z2\nM5\nG0 X0 Y0".to_string(),
+            pen_up: "G0 Z2".to_string(),
+            pen_down: "G1 Z-0.5 F{feed_rate}".to_string(),
+            feed_rate: 300.0,
+            limits: None,
+        }
+    }
+
+    fn render(&self, template: &str) -> String {
+        template.replace("{feed_rate}", &format_number(self.feed_rate))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GcodeOptions {
+    pub profile: MachineProfile,
+    pub scale: f64,
+    pub flip_y: bool,
+    pub origin: PointF,
+    pub comments: Vec<String>,
+    pub units: Option<Units>,
+}
+
+impl Default for GcodeOptions {
+    fn default() -> GcodeOptions {
+        GcodeOptions {
+            profile: MachineProfile::grbl_pen_plotter(),
+            scale: 1.0,
+            flip_y: true,
+            origin: PointF { x: 0.0, y: 0.0 },
+            comments: Vec::new(),
+            units: None,
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GcodeWriteError {
+    #[error("{0}")]
+    OutOfBounds(String),
+}
+
+pub(crate) fn format_number(value: f64) -> String {
+    let formatted = format!("{:.3}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    match trimmed {
+        "-0" | "" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+pub fn write_gcode(paths: &[Vec<Edge>], options: &GcodeOptions) -> Result<String, GcodeWriteError> {
+    let points = paths
+        .iter()
+        .map(|path| path.iter().copied().map(PointF::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    write_gcode_points(&points, options)
+}
+
+pub fn write_gcode_points(
+    paths: &[Vec<PointF>],
+    options: &GcodeOptions,
+) -> Result<String, GcodeWriteError> {
+    let profile = &options.profile;
+    let mut lines = std::iter::once(&profile.name)
+        .chain(&options.comments)
+        .flat_map(|comment| comment_lines(comment))
+        .map(|line| format!("; {}", line))
+        .collect::<Vec<_>>();
+
+    lines.extend(profile.render(&profile.header).lines().map(str::to_string));
+
+    let scale = options.scale
+        * options
+            .units
+            .map_or(1.0, |units| units.factor(Units::Millimeters));
+    let to_machine = |point: &PointF| -> Result<(f64, f64), GcodeWriteError> {
+        let x = point.x * scale + options.origin.x;
+        let y = point.y * scale * if options.flip_y { -1.0 } else { 1.0 } + options.origin.y;
+
+        if let Some(limits) = profile.limits {
+            if x < limits.min_x || x > limits.max_x || y < limits.min_y || y > limits.max_y {
+                return Err(GcodeWriteError::OutOfBounds(format!(
+                    "Point ({}, {}) is outside the limits of {}",
+                    format_number(x),
+                    format_number(y),
+                    profile.name
+                )));
+            }
+        }
+
+        Ok((x, y))
+    };
+
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        let (x, y) = to_machine(&path[0])?;
+
+        lines.push(format!("G0 X{} Y{}", format_number(x), format_number(y)));
+        lines.extend(
+            profile
+                .render(&profile.pen_down)
+                .lines()
+                .map(str::to_string),
+        );
+
+        for point in &path[1..] {
+            let (x, y) = to_machine(point)?;
+
+            lines.push(format!(
+                "G1 X{} Y{} F{}",
+                format_number(x),
+                format_number(y),
+                format_number(profile.feed_rate)
+            ));
+        }
+
+        lines.extend(profile.render(&profile.pen_up).lines().map(str::to_string));
+    }
+
+    lines.extend(profile.render(&profile.footer).lines().map(str::to_string));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToolFile {
+    pub tool: Option<String>,
+    pub gcode: String,
+}
+
+pub fn write_gcode_by_tool(
+    layers: &[StyledPaths],
+    options: &GcodeOptions,
+) -> Result<Vec<ToolFile>, GcodeWriteError> {
+    let mut tools: Vec<(Option<String>, Vec<Vec<PointF>>)> = Vec::new();
+
+    for layer in layers {
+        match tools.iter_mut().find(|(tool, _)| *tool == layer.style.tool) {
+            Some((_, paths)) => paths.extend(layer.paths.iter().cloned()),
+            None => tools.push((layer.style.tool.clone(), layer.paths.clone())),
+        }
+    }
+
+    tools
+        .into_iter()
+        .map(|(tool, paths)| {
+            let mut options = options.clone();
+
+            if let Some(tool) = &tool {
+                options.comments.push(format!("Tool: {}", tool));
+            }
+
+            Ok(ToolFile {
+                gcode: write_gcode_points(&paths, &options)?,
+                tool,
+            })
+        })
+        .collect()
+}
+
+impl HersheyGlyph {
+    pub fn to_gcode(&self, options: &GcodeOptions) -> Result<String, GcodeWriteError> {
+        write_gcode(&self.paths, options)
+    }
+}
+
+impl TextLayout {
+    pub fn to_gcode(&self, options: &GcodeOptions) -> Result<String, GcodeWriteError> {
+        write_gcode(&self.paths(), options)
+    }
+}
+
+impl HersheyFont {
+    pub fn text_to_gcode(
+        &self,
+        text: &str,
+        options: &GcodeOptions,
+    ) -> Result<String, GcodeWriteError> {
+        let mut options = options.clone();
+
+        if let Some(meta) = self.meta() {
+            options.comments.extend(meta.lines());
+        }
+
+        options.flip_y = options.flip_y != self.y_up();
+        self.layout_text(text).to_gcode(&options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+
+    fn paths() -> Vec<Vec<Edge>> {
+        vec![vec![Edge { x: 0, y: 0 }, Edge { x: 10, y: 5 }]]
+    }
+
+    #[test]
+    fn write_gcode_works() {
+        let gcode = write_gcode(
+            &paths(),
+            &GcodeOptions {
+                scale: 0.5,
+                comments: vec!["Name: futural".to_string()],
+                ..GcodeOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            gcode,
+            "; grbl pen plotter\n; Name: futural\nG21\nG90\nG0 Z5\nG0 X0 Y0\nG1 Z0 F1000\nG1 X5 Y-2.5 F1000\nG0 Z5\nG0 Z5\nG0 X0 Y0\n"
+        );
+    }
+
+    #[test]
+    fn write_gcode_keeps_comment_lines_commented() {
+        let gcode = write_gcode(
+            &[],
+            &GcodeOptions {
+                comments: vec!["Name: x\nG0 X100\r\nM3\u{7}".to_string()],
+                ..GcodeOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert!(gcode.starts_with("; grbl pen plotter\n; Name: x\n; G0 X100\n; M3\nG21\n"));
+    }
+
+    #[test]
+    fn write_gcode_uses_profile_pen_commands() {
+        let gcode = write_gcode(
+            &paths(),
+            &GcodeOptions {
+                profile: MachineProfile::laser_m4(),
+                ..GcodeOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert!(gcode.contains("M4 S1000\nG1 X10 Y-5 F1500\nM5\n"));
+    }
+
+    #[test]
+    fn write_gcode_converts_units_to_millimeters() {
+        let gcode = write_gcode(
+            &paths(),
+            &GcodeOptions {
+                scale: 0.1,
+                units: Some(Units::Inches),
+                ..GcodeOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert!(gcode.contains("G1 X25.4 Y-12.7 F1000\n"));
+    }
+
+    #[test]
+    fn write_gcode_returns_error_outside_limits() {
+        let result = write_gcode(
+            &paths(),
+            &GcodeOptions {
+                profile: MachineProfile {
+                    limits: Some(Limits {
+                        min_x: 0.0,
+                        min_y: -1.0,
+                        max_x: 100.0,
+                        max_y: 100.0,
+                    }),
+                    ..MachineProfile::cnc_spindle()
+                },
+                ..GcodeOptions::default()
+            },
+        );
+
+        assert!(matches!(result, Err(GcodeWriteError::OutOfBounds(_))));
+    }
+
+    #[test]
+    fn write_gcode_applies_origin() {
+        let gcode = write_gcode(
+            &paths(),
+            &GcodeOptions {
+                origin: PointF { x: 20.0, y: 30.0 },
+                ..GcodeOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert!(gcode.contains("G0 X20 Y30\nG1 Z0 F1000\nG1 X30 Y25 F1000\n"));
+    }
+
+    #[test]
+    fn text_layout_to_gcode_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let gcode = font
+            .layout_text("!!")
+            .to_gcode(&GcodeOptions {
+                profile: MachineProfile::laser_m3(),
+                ..GcodeOptions::default()
+            })
+            .unwrap();
+
+        assert!(gcode.contains("G0 X5 Y10\nM3 S1000\nG1 X5 Y-10 F1500\nM5\n"));
+        assert!(gcode.contains("G0 X15 Y10\n"));
+        assert_eq!(
+            font.get_glyph('!')
+                .unwrap()
+                .to_gcode(&GcodeOptions::default())
+                .unwrap(),
+            write_gcode(
+                &font.get_glyph('!').unwrap().paths,
+                &GcodeOptions::default()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn text_to_gcode_includes_metadata() {
+        let font = HersheyFont::new("name: futural\n  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let gcode = font.text_to_gcode("!", &GcodeOptions::default()).unwrap();
+
+        assert!(gcode.contains("; Name: futural\n"));
+        assert!(gcode.contains("G0 X5 Y10\n"));
+    }
+
+    #[test]
+    fn write_gcode_by_tool_splits_files() {
+        use crate::style::Style;
+
+        let layer = |tool: Option<&str>, x: f64| StyledPaths {
+            style: Style {
+                tool: tool.map(str::to_string),
+                ..Style::default()
+            },
+            paths: vec![vec![PointF { x, y: 0.0 }, PointF { x, y: 1.0 }]],
+        };
+        let files = write_gcode_by_tool(
+            &[
+                layer(Some("fine"), 1.0),
+                layer(None, 2.0),
+                layer(Some("fine"), 3.0),
+            ],
+            &GcodeOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].tool.as_deref(), Some("fine"));
+        assert!(files[0].gcode.contains("; Tool: fine\n"));
+        assert!(files[0].gcode.contains("G0 X1 Y0") && files[0].gcode.contains("G0 X3 Y0"));
+        assert!(files[1].gcode.contains("G0 X2 Y0"));
+    }
+
+    #[test]
+    fn text_to_gcode_follows_coordinate_system() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHRR").unwrap();
+        let mut y_up = font.clone();
+        y_up.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            y_up.text_to_gcode("!\n!", &GcodeOptions::default())
+                .unwrap(),
+            font.text_to_gcode("!\n!", &GcodeOptions::default())
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::layout::TextLayout;
+use crate::stats::path_length;
+use crate::{Edge, HersheyGlyph, PointF};
+
+fn lerp(a: Edge, b: Edge, t: f64) -> PointF {
+    PointF {
+        x: a.x as f64 + (b.x - a.x) as f64 * t,
+        y: a.y as f64 + (b.y - a.y) as f64 * t,
+    }
+}
+
+pub fn point_at_distance(path: &[Edge], distance: f64) -> Option<PointF> {
+    let mut remaining = distance.max(0.0);
+
+    for pair in path.windows(2) {
+        let length = pair[0].distance_to(&pair[1]);
+
+        if length > 0.0 && remaining <= length {
+            return Some(lerp(pair[0], pair[1], remaining / length));
+        }
+
+        remaining -= length;
+    }
+
+    path.last().copied().map(PointF::from)
+}
+
+pub fn resample_path(path: &[Edge], spacing: f64) -> Vec<PointF> {
+    let length = path_length(path);
+
+    if path.is_empty() || spacing <= 0.0 || length == 0.0 {
+        return path.iter().copied().map(PointF::from).take(1).collect();
+    }
+
+    let steps = (length / spacing).floor() as usize;
+    let mut points = (0..=steps)
+        .filter_map(|step| point_at_distance(path, step as f64 * spacing))
+        .collect::<Vec<_>>();
+
+    if steps as f64 * spacing < length - 1e-9 {
+        points.extend(path.last().copied().map(PointF::from));
+    }
+
+    points
+}
+
+impl HersheyGlyph {
+    pub fn path_length(&self) -> f64 {
+        self.paths().map(path_length).sum()
+    }
+
+    pub fn point_at(&self, t: f64) -> Option<PointF> {
+        let mut remaining = t.clamp(0.0, 1.0) * self.path_length();
+        let paths = self
+            .paths()
+            .filter(|path| !path.is_empty())
+            .collect::<Vec<_>>();
+
+        for (i, path) in paths.iter().enumerate() {
+            let length = path_length(path);
+
+            if remaining <= length || i + 1 == paths.len() {
+                return point_at_distance(path, remaining);
+            }
+
+            remaining -= length;
+        }
+
+        None
+    }
+
+    pub fn resample(&self, spacing: f64) -> Vec<Vec<PointF>> {
+        self.paths()
+            .filter(|path| !path.is_empty())
+            .map(|path| resample_path(path, spacing))
+            .collect()
+    }
+}
+
+impl TextLayout {
+    pub fn resample(&self, spacing: f64) -> Vec<Vec<PointF>> {
+        self.paths()
+            .iter()
+            .filter(|path| !path.is_empty())
+            .map(|path| resample_path(path, spacing))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph() -> HersheyGlyph {
+        HersheyGlyph::new(
+            -5,
+            5,
+            vec![
+                vec![
+                    Edge { x: 0, y: 0 },
+                    Edge { x: 3, y: 0 },
+                    Edge { x: 3, y: 4 },
+                ],
+                vec![Edge { x: 10, y: 0 }, Edge { x: 13, y: 0 }],
+            ],
+        )
+    }
+
+    #[test]
+    fn path_length_and_point_at_work() {
+        let glyph = glyph();
+
+        assert_eq!(glyph.path_length(), 10.0);
+        assert_eq!(glyph.point_at(0.0), Some(PointF { x: 0.0, y: 0.0 }));
+        assert_eq!(glyph.point_at(0.5), Some(PointF { x: 3.0, y: 2.0 }));
+        assert_eq!(glyph.point_at(0.8), Some(PointF { x: 11.0, y: 0.0 }));
+        assert_eq!(glyph.point_at(2.0), Some(PointF { x: 13.0, y: 0.0 }));
+        assert_eq!(HersheyGlyph::new(0, 0, Vec::new()).point_at(0.5), None);
+    }
+
+    #[test]
+    fn resample_works() {
+        let resampled = glyph().resample(2.0);
+
+        assert_eq!(
+            resampled[0],
+            vec![
+                PointF { x: 0.0, y: 0.0 },
+                PointF { x: 2.0, y: 0.0 },
+                PointF { x: 3.0, y: 1.0 },
+                PointF { x: 3.0, y: 3.0 },
+                PointF { x: 3.0, y: 4.0 },
+            ]
+        );
+        assert_eq!(resampled[1].len(), 3);
+        assert_eq!(
+            resample_path(&[Edge { x: 1, y: 1 }], 2.0),
+            vec![PointF { x: 1.0, y: 1.0 }]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+const ROMAN_SIMPLEX: &str = "Roman simplex";
+const GREEK_SIMPLEX: &str = "Greek simplex";
+const SYMBOL: &str = "symbol";
+
+const GREEK_NAMES: [&str; 24] = [
+    "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+    "lambda", "mu", "nu", "xi", "omicron", "pi", "rho", "sigma", "tau", "upsilon", "phi", "chi",
+    "psi", "omega",
+];
+
+struct Range {
+    start: u32,
+    chars: &'static str,
+    set: &'static str,
+}
+
+const RANGES: &[Range] = &[
+    Range {
+        start: 501,
+        chars: "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+        set: ROMAN_SIMPLEX,
+    },
+    Range {
+        start: 527,
+        chars: "ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡΣΤΥΦΧΨΩ",
+        set: GREEK_SIMPLEX,
+    },
+    Range {
+        start: 601,
+        chars: "abcdefghijklmnopqrstuvwxyz",
+        set: ROMAN_SIMPLEX,
+    },
+    Range {
+        start: 627,
+        chars: "αβγδεζηθικλμνξοπρστυφχψω",
+        set: GREEK_SIMPLEX,
+    },
+    Range {
+        start: 700,
+        chars: "0123456789",
+        set: ROMAN_SIMPLEX,
+    },
+];
+
+const SINGLES: &[(u32, char, &str, &str)] = &[
+    (699, ' ', "space", ROMAN_SIMPLEX),
+    (710, '.', "full stop", ROMAN_SIMPLEX),
+    (711, ',', "comma", ROMAN_SIMPLEX),
+    (712, ':', "colon", ROMAN_SIMPLEX),
+    (713, ';', "semicolon", ROMAN_SIMPLEX),
+    (714, '!', "exclamation mark", ROMAN_SIMPLEX),
+    (715, '?', "question mark", ROMAN_SIMPLEX),
+    (717, '"', "quotation mark", ROMAN_SIMPLEX),
+    (718, '°', "degree sign", ROMAN_SIMPLEX),
+    (719, '$', "dollar sign", ROMAN_SIMPLEX),
+    (720, '/', "solidus", ROMAN_SIMPLEX),
+    (721, '(', "left parenthesis", ROMAN_SIMPLEX),
+    (722, ')', "right parenthesis", ROMAN_SIMPLEX),
+    (723, '|', "vertical bar", ROMAN_SIMPLEX),
+    (724, '-', "hyphen", ROMAN_SIMPLEX),
+    (725, '+', "plus sign", ROMAN_SIMPLEX),
+    (726, '=', "equals sign", ROMAN_SIMPLEX),
+    (730, '`', "grave accent", ROMAN_SIMPLEX),
+    (731, '\'', "apostrophe", ROMAN_SIMPLEX),
+    (733, '#', "number sign", ROMAN_SIMPLEX),
+    (734, '&', "ampersand", ROMAN_SIMPLEX),
+    (804, '\\', "reverse solidus", ROMAN_SIMPLEX),
+    (999, '_', "low line", ROMAN_SIMPLEX),
+    (2219, '*', "asterisk", SYMBOL),
+    (2223, '[', "left square bracket", SYMBOL),
+    (2224, ']', "right square bracket", SYMBOL),
+    (2225, '{', "left curly bracket", SYMBOL),
+    (2226, '}', "right curly bracket", SYMBOL),
+    (2233, '±', "plus-minus sign", SYMBOL),
+    (2235, '×', "multiplication sign", SYMBOL),
+    (2237, '÷', "division sign", SYMBOL),
+    (2241, '<', "less-than sign", SYMBOL),
+    (2242, '>', "greater-than sign", SYMBOL),
+    (2246, '~', "tilde", SYMBOL),
+    (2262, '^', "circumflex accent", SYMBOL),
+    (2270, '∞', "infinity", SYMBOL),
+    (2271, '%', "percent sign", SYMBOL),
+    (2273, '@', "commercial at", SYMBOL),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphInfo {
+    pub number: u32,
+    pub description: String,
+}
+
+pub(crate) fn lookup(number: u32) -> Option<(char, String, &'static str)> {
+    for range in RANGES {
+        let Some(offset) = number.checked_sub(range.start) else {
+            continue;
+        };
+
+        if let Some(c) = range.chars.chars().nth(offset as usize) {
+            let name = if range.set == GREEK_SIMPLEX {
+                let name = GREEK_NAMES[offset as usize];
+
+                if c.is_uppercase() {
+                    name[..1].to_uppercase() + &name[1..]
+                } else {
+                    name.to_string()
+                }
+            } else {
+                c.to_string()
+            };
+
+            return Some((c, name, range.set));
+        }
+    }
+
+    SINGLES
+        .iter()
+        .find(|(single, ..)| *single == number)
+        .map(|(_, c, name, set)| (*c, name.to_string(), *set))
+}
+
+pub fn glyph_info(number: u32) -> Option<GlyphInfo> {
+    lookup(number).map(|(_, name, set)| GlyphInfo {
+        number,
+        description: format!("{}, {}", name, set),
+    })
+}
+
+pub fn number_to_unicode(number: u32) -> Option<char> {
+    lookup(number).map(|(c, ..)| c)
+}
+
+pub fn unicode_to_number(c: char) -> Option<u32> {
+    RANGES
+        .iter()
+        .find_map(|range| {
+            let offset = range.chars.chars().position(|letter| letter == c)?;
+
+            Some(range.start + offset as u32)
+        })
+        .or_else(|| {
+            SINGLES
+                .iter()
+                .find(|(_, single, ..)| *single == c)
+                .map(|(number, ..)| *number)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_info_works() {
+        assert_eq!(
+            glyph_info(501),
+            Some(GlyphInfo {
+                number: 501,
+                description: "A, Roman simplex".to_string(),
+            })
+        );
+        assert_eq!(glyph_info(550).unwrap().description, "Omega, Greek simplex");
+        assert_eq!(glyph_info(642).unwrap().description, "pi, Greek simplex");
+        assert_eq!(
+            glyph_info(2273).unwrap().description,
+            "commercial at, symbol"
+        );
+    }
+
+    #[test]
+    fn number_to_unicode_works() {
+        assert_eq!(number_to_unicode(526), Some('Z'));
+        assert_eq!(number_to_unicode(627), Some('α'));
+        assert_eq!(number_to_unicode(804), Some('\\'));
+        assert_eq!(number_to_unicode(3000), None);
+    }
+
+    #[test]
+    fn unicode_to_number_works() {
+        assert_eq!(unicode_to_number('a'), Some(601));
+        assert_eq!(unicode_to_number('9'), Some(709));
+        assert_eq!(unicode_to_number('@'), Some(2273));
+        assert_eq!(unicode_to_number('°'), Some(718));
+        assert_eq!(unicode_to_number('∞'), Some(2270));
+        assert_eq!(unicode_to_number('é'), None);
+    }
+
+    #[test]
+    fn glyph_info_returns_none_for_unknown_numbers() {
+        assert_eq!(glyph_info(1), None);
+        assert_eq!(glyph_info(651), None);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::layout::{Direction, LayoutLine, TextLayout};
+use crate::raster::segment_distance;
+use crate::{HersheyGlyph, PointF};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PathLocation {
+    pub path: usize,
+    pub segment: usize,
+    pub point: PointF,
+}
+
+fn closest_on_segment(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> PointF {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+
+    PointF {
+        x: a.0 + t * dx,
+        y: a.1 + t * dy,
+    }
+}
+
+impl HersheyGlyph {
+    pub fn nearest_point(&self, x: f64, y: f64) -> Option<(f64, PathLocation)> {
+        self.paths()
+            .enumerate()
+            .flat_map(|(i, path)| {
+                let points = path
+                    .iter()
+                    .map(|edge| (edge.x as f64, edge.y as f64))
+                    .collect::<Vec<_>>();
+                let segments = match points.len() {
+                    1 => vec![(points[0], points[0])],
+                    _ => points.windows(2).map(|pair| (pair[0], pair[1])).collect(),
+                };
+
+                segments
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(segment, (a, b))| {
+                        (
+                            segment_distance((x, y), a, b),
+                            PathLocation {
+                                path: i,
+                                segment,
+                                point: closest_on_segment((x, y), a, b),
+                            },
+                        )
+                    })
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+    }
+}
+
+impl TextLayout {
+    pub fn hit_test(&self, x: f64, y: f64) -> Option<usize> {
+        let vertical = self.direction == Direction::TopToBottom;
+        let distance = |line: &LayoutLine| {
+            if vertical {
+                let column = self.glyphs[line.glyphs.start].advance as f64;
+
+                (line.x as f64 + column / 2.0 - x).abs()
+            } else {
+                (line.y as f64 - y).abs()
+            }
+        };
+        let line = self
+            .lines
+            .iter()
+            .filter(|line| !line.glyphs.is_empty())
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))?;
+
+        line.glyphs
+            .clone()
+            .map(|i| &self.glyphs[i])
+            .find(|glyph| {
+                if vertical {
+                    let top = glyph.y as f64 - glyph.advance as f64 / 2.0;
+
+                    y >= top && y < top + glyph.advance as f64
+                } else {
+                    x >= glyph.x as f64 && x < (glyph.x + glyph.advance) as f64
+                }
+            })
+            .map(|glyph| glyph.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::LayoutOptions;
+    use crate::{Edge, HersheyFont};
+
+    #[test]
+    fn nearest_point_works() {
+        let glyph = HersheyGlyph::new(
+            -5,
+            5,
+            vec![
+                vec![Edge { x: 0, y: -10 }, Edge { x: 0, y: 10 }],
+                vec![Edge { x: 4, y: 0 }],
+            ],
+        );
+        let (distance, location) = glyph.nearest_point(2.0, 5.0).unwrap();
+
+        assert_eq!(distance, 2.0);
+        assert_eq!(
+            location,
+            PathLocation {
+                path: 0,
+                segment: 0,
+                point: PointF { x: 0.0, y: 5.0 }
+            }
+        );
+        assert_eq!(glyph.nearest_point(4.0, 1.0).unwrap().1.path, 1);
+        assert!(HersheyGlyph::new(0, 0, Vec::new())
+            .nearest_point(0.0, 0.0)
+            .is_none());
+    }
+
+    #[test]
+    fn hit_test_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text("!!\n !");
+
+        assert_eq!(layout.hit_test(3.0, 0.0), Some(0));
+        assert_eq!(font.layout_text("\u{e9}!").hit_test(3.0, 0.0), Some(1));
+        assert_eq!(layout.hit_test(12.0, -4.0), Some(1));
+        assert_eq!(layout.hit_test(20.0, 30.0), Some(4));
+        assert_eq!(layout.hit_test(40.0, 0.0), None);
+    }
+
+    #[test]
+    fn hit_test_uses_columns_for_vertical_layouts() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text_with_options(
+            "!!\n!",
+            &LayoutOptions {
+                direction: Direction::TopToBottom,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(layout.hit_test(10.0, 0.0), Some(0));
+        assert_eq!(layout.hit_test(8.0, 25.0), Some(1));
+        assert_eq!(layout.hit_test(30.0, -5.0), Some(3));
+        assert_eq!(layout.hit_test(30.0, 25.0), None);
+    }
+}
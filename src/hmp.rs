@@ -0,0 +1,257 @@
+use crate::{HersheyFont, HersheyFontNewError};
+
+const LINE_WIDTH: usize = 72;
+const MAX_RANGE_LENGTH: u32 = 10_000;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HersheyMap {
+    pub numbers: Vec<u32>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyMapParseError {
+    #[error("{0}")]
+    InvalidNumber(String),
+    #[error("{0}")]
+    RangeTooLong(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyMapWriteError {
+    #[error("{0}")]
+    UnmappedChar(String),
+}
+
+impl HersheyMap {
+    pub fn parse(data: &str) -> Result<HersheyMap, HersheyMapParseError> {
+        let mut numbers = Vec::new();
+
+        for (i, line) in data.lines().enumerate() {
+            let parse_error = |token: &str| {
+                HersheyMapParseError::InvalidNumber(format!(
+                    "Error parsing line {}: invalid glyph number or range {}",
+                    i + 1,
+                    token
+                ))
+            };
+
+            for token in line.split_whitespace() {
+                let parse = |value: &str| value.parse::<u32>().map_err(|_| parse_error(token));
+
+                match token.split_once('-') {
+                    Some((start, end)) => {
+                        let (start, end) = (parse(start)?, parse(end)?);
+
+                        if start.abs_diff(end) >= MAX_RANGE_LENGTH {
+                            return Err(HersheyMapParseError::RangeTooLong(format!(
+                                "Error parsing line {}: range {} spans more than {} glyphs",
+                                i + 1,
+                                token,
+                                MAX_RANGE_LENGTH
+                            )));
+                        }
+
+                        if start <= end {
+                            numbers.extend(start..=end);
+                        } else {
+                            numbers.extend((end..=start).rev());
+                        }
+                    }
+                    None => numbers.push(parse(token)?),
+                }
+            }
+        }
+
+        Ok(HersheyMap { numbers })
+    }
+
+    pub fn to_hmp(&self) -> String {
+        write_hmp(&self.numbers)
+    }
+}
+
+impl std::str::FromStr for HersheyMap {
+    type Err = HersheyMapParseError;
+
+    fn from_str(data: &str) -> Result<HersheyMap, HersheyMapParseError> {
+        HersheyMap::parse(data)
+    }
+}
+
+impl HersheyFont {
+    pub fn with_map(data: &str, map: &HersheyMap) -> Result<HersheyFont, HersheyFontNewError> {
+        let font = HersheyFont::new(data)?;
+        let glyphs = map
+            .numbers
+            .iter()
+            .map(|number| {
+                font.get_glyph_by_number(*number).cloned().map_err(|_| {
+                    HersheyFontNewError::GlyphNotFound(format!(
+                        "Glyph number {} in map not found in font",
+                        number
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, HersheyFontNewError>>()?;
+
+        Ok(HersheyFont::from_parts(font.metadata, glyphs))
+    }
+
+    pub fn to_hmp(&self) -> Result<String, HersheyMapWriteError> {
+        let chars = (0..self.glyphs.len())
+            .flat_map(|i| self.chars_for_glyph(i))
+            .collect::<Vec<_>>();
+
+        if let Some(c) = chars.iter().find(|c| **c < ' ') {
+            return Err(HersheyMapWriteError::UnmappedChar(format!(
+                "Char {:?} is below the first char of a Hershey map",
+                c
+            )));
+        }
+
+        let Some(last) = chars.into_iter().max() else {
+            return Ok(String::new());
+        };
+
+        let numbers = (' '..=last)
+            .map(|c| {
+                self.get_glyph_checked(c).map(|glyph| glyph.number).ok_or(
+                    HersheyMapWriteError::UnmappedChar(format!(
+                        "Char {:?} has no glyph, Hershey maps cover a contiguous range",
+                        c
+                    )),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(write_hmp(&numbers))
+    }
+}
+
+fn format_run(start: u32, end: u32) -> String {
+    if start == end {
+        start.to_string()
+    } else {
+        format!("{}-{}", start, end)
+    }
+}
+
+pub fn write_hmp(numbers: &[u32]) -> String {
+    let mut entries = Vec::new();
+    let mut run: Option<(u32, u32)> = None;
+
+    for number in numbers {
+        run = match run {
+            Some((start, end)) if end.checked_add(1) == Some(*number) => Some((start, *number)),
+            Some((start, end)) => {
+                entries.push(format_run(start, end));
+                Some((*number, *number))
+            }
+            None => Some((*number, *number)),
+        };
+    }
+
+    if let Some((start, end)) = run {
+        entries.push(format_run(start, end));
+    }
+
+    let mut output = String::new();
+    let mut line_length = 0;
+
+    for entry in entries {
+        if line_length > 0 && line_length + 1 + entry.len() > LINE_WIDTH {
+            output.push('\n');
+            line_length = 0;
+        } else if line_length > 0 {
+            output.push(' ');
+            line_length += 1;
+        }
+
+        line_length += entry.len();
+        output.push_str(&entry);
+    }
+
+    if !output.is_empty() {
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapping::CharMapping;
+    use std::collections::HashMap;
+
+    #[test]
+    fn write_hmp_works() {
+        let numbers = [699, 714, 700, 701, 702, 703, 2241];
+
+        assert_eq!(write_hmp(&numbers), "699 714 700-703 2241\n");
+    }
+
+    #[test]
+    fn parse_works() {
+        let map = HersheyMap::parse("699 714\n700-703 2241\n  5-3").unwrap();
+
+        assert_eq!(
+            map.numbers,
+            vec![699, 714, 700, 701, 702, 703, 2241, 5, 4, 3]
+        );
+        assert!("700-x".parse::<HersheyMap>().is_err());
+    }
+
+    #[test]
+    fn parse_rejects_huge_ranges() {
+        assert!(matches!(
+            HersheyMap::parse("1-4000000000"),
+            Err(HersheyMapParseError::RangeTooLong(_))
+        ));
+        assert!(matches!(
+            HersheyMap::parse("4000000000-1"),
+            Err(HersheyMapParseError::RangeTooLong(_))
+        ));
+    }
+
+    #[test]
+    fn font_to_hmp_uses_active_mapping() {
+        let data = "  700  3JZRHR\\\n  699  1JZ\n  710  1KY";
+        let font = HersheyFont::new(data).unwrap();
+        let mapped = font
+            .clone()
+            .with_mapping(CharMapping::HersheyMapFile(HersheyMap {
+                numbers: vec![710, 700],
+            }));
+        let sparse = font
+            .clone()
+            .with_mapping(CharMapping::Custom(HashMap::from([(' ', 0), ('"', 1)])));
+
+        assert_eq!(font.to_hmp().unwrap(), "700 699 710\n");
+        assert_eq!(mapped.to_hmp().unwrap(), "710 700\n");
+        assert!(matches!(
+            sparse.to_hmp(),
+            Err(HersheyMapWriteError::UnmappedChar(_))
+        ));
+    }
+
+    #[test]
+    fn with_map_resolves_glyphs_through_map() {
+        let data = "  700  3JZRHR\\\n  699  1JZ\n  710  1KY";
+        let font = HersheyFont::with_map(data, &HersheyMap::parse("699 710 700").unwrap()).unwrap();
+
+        assert_eq!(font.get_glyph(' ').unwrap().number, 699);
+        assert_eq!(font.get_glyph('!').unwrap().number, 710);
+        assert_eq!(font.get_glyph('"').unwrap().paths.len(), 1);
+        assert!(HersheyFont::with_map(data, &HersheyMap::parse("701").unwrap()).is_err());
+    }
+
+    #[test]
+    fn write_hmp_wraps_long_lines() {
+        let numbers = (0..40).map(|i| 1000 + i * 2).collect::<Vec<_>>();
+        let output = write_hmp(&numbers);
+
+        assert!(output.lines().count() > 1);
+        assert!(output.lines().all(|line| line.len() <= LINE_WIDTH));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::layout::TextLayout;
+use crate::style::StyledPaths;
+use crate::units::Units;
+use crate::{Edge, HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HpglOptions {
+    pub scale: f64,
+    pub flip_y: bool,
+    pub origin: PointF,
+    pub pen: u32,
+    pub velocity: Option<f64>,
+    pub units: Option<Units>,
+}
+
+const PLOTTER_UNITS_PER_MM: f64 = 40.0;
+
+impl Default for HpglOptions {
+    fn default() -> HpglOptions {
+        HpglOptions {
+            scale: 1.0,
+            flip_y: true,
+            origin: PointF { x: 0.0, y: 0.0 },
+            pen: 1,
+            velocity: None,
+            units: None,
+        }
+    }
+}
+
+fn to_plotter(point: &PointF, options: &HpglOptions) -> (i64, i64) {
+    let scale = options.scale
+        * options.units.map_or(1.0, |units| {
+            units.factor(Units::Millimeters) * PLOTTER_UNITS_PER_MM
+        });
+    let x = point.x * scale + options.origin.x;
+    let y = point.y * scale * if options.flip_y { -1.0 } else { 1.0 } + options.origin.y;
+
+    (x.round() as i64, y.round() as i64)
+}
+
+fn push_paths(commands: &mut Vec<String>, paths: &[Vec<PointF>], options: &HpglOptions) {
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        let (x, y) = to_plotter(&path[0], options);
+
+        commands.push(format!("PU{},{};", x, y));
+
+        if path.len() > 1 {
+            let coordinates = path[1..]
+                .iter()
+                .map(|point| {
+                    let (x, y) = to_plotter(point, options);
+                    format!("{},{}", x, y)
+                })
+                .collect::<Vec<_>>();
+
+            commands.push(format!("PD{};", coordinates.join(",")));
+        }
+    }
+}
+
+fn write_commands(body: Vec<String>, options: &HpglOptions) -> String {
+    let mut commands = vec!["IN;".to_string(), "PA;".to_string()];
+
+    if let Some(velocity) = options.velocity {
+        commands.push(format!("VS{};", crate::gcode::format_number(velocity)));
+    }
+
+    commands.extend(body);
+    commands.push("PU;".to_string());
+    commands.push("SP0;".to_string());
+
+    commands.join("\n") + "\n"
+}
+
+pub fn write_hpgl(paths: &[Vec<Edge>], options: &HpglOptions) -> String {
+    let points = paths
+        .iter()
+        .map(|path| path.iter().copied().map(PointF::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    write_hpgl_points(&points, options)
+}
+
+pub fn write_hpgl_points(paths: &[Vec<PointF>], options: &HpglOptions) -> String {
+    let mut body = vec![format!("SP{};", options.pen)];
+
+    push_paths(&mut body, paths, options);
+    write_commands(body, options)
+}
+
+pub fn write_hpgl_layers(layers: &[StyledPaths], options: &HpglOptions) -> String {
+    let mut body = Vec::new();
+
+    for layer in layers {
+        body.push(format!("SP{};", layer.style.pen));
+        push_paths(&mut body, &layer.paths, options);
+    }
+
+    write_commands(body, options)
+}
+
+impl HersheyGlyph {
+    pub fn to_hpgl(&self, options: &HpglOptions) -> String {
+        write_hpgl(&self.paths, options)
+    }
+}
+
+impl TextLayout {
+    pub fn to_hpgl(&self, options: &HpglOptions) -> String {
+        write_hpgl(&self.paths(), options)
+    }
+}
+
+impl HersheyFont {
+    pub fn text_to_hpgl(&self, text: &str, options: &HpglOptions) -> String {
+        let options = HpglOptions {
+            flip_y: options.flip_y != self.y_up(),
+            ..options.clone()
+        };
+
+        self.layout_text(text).to_hpgl(&options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+    use crate::style::Style;
+
+    #[test]
+    fn write_hpgl_works() {
+        let paths = vec![vec![
+            Edge { x: 0, y: 0 },
+            Edge { x: 10, y: 5 },
+            Edge { x: 10, y: -5 },
+        ]];
+        let hpgl = write_hpgl(
+            &paths,
+            &HpglOptions {
+                scale: 40.0,
+                origin: PointF { x: 100.0, y: 0.0 },
+                velocity: Some(10.0),
+                ..HpglOptions::default()
+            },
+        );
+
+        assert_eq!(
+            hpgl,
+            "IN;\nPA;\nVS10;\nSP1;\nPU100,0;\nPD500,-200,500,200;\nPU;\nSP0;\n"
+        );
+    }
+
+    #[test]
+    fn write_hpgl_converts_units_to_plotter_units() {
+        let paths = vec![vec![Edge { x: 1, y: 0 }, Edge { x: 1, y: 2 }]];
+        let hpgl = write_hpgl(
+            &paths,
+            &HpglOptions {
+                scale: 0.5,
+                units: Some(Units::Millimeters),
+                ..HpglOptions::default()
+            },
+        );
+
+        assert!(hpgl.contains("PU20,0;\nPD20,-40;\n"));
+    }
+
+    #[test]
+    fn write_hpgl_layers_selects_pens() {
+        let layer = |pen: u32, x: f64| StyledPaths {
+            style: Style {
+                pen,
+                ..Style::default()
+            },
+            paths: vec![vec![PointF { x, y: 0.0 }, PointF { x, y: 1.0 }]],
+        };
+        let hpgl = write_hpgl_layers(&[layer(1, 0.0), layer(2, 5.0)], &HpglOptions::default());
+
+        assert!(hpgl.contains("SP1;\nPU0,0;\nPD0,-1;\nSP2;\nPU5,0;\nPD5,-1;\n"));
+    }
+
+    #[test]
+    fn text_layout_to_hpgl_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let hpgl = font.layout_text("!!").to_hpgl(&HpglOptions::default());
+
+        assert!(hpgl.contains("PU5,10;\nPD5,-10;\nPU15,10;\nPD15,-10;\n"));
+    }
+
+    #[test]
+    fn text_to_hpgl_follows_coordinate_system() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHRR").unwrap();
+        let mut y_up = font.clone();
+        y_up.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            y_up.text_to_hpgl("!\n!", &HpglOptions::default()),
+            font.text_to_hpgl("!\n!", &HpglOptions::default())
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::text::font_height;
+use crate::{HersheyFont, HersheyGlyph};
+
+const SCRIPT: &str = r#"const input = document.getElementById("preview-text");
+const output = document.getElementById("preview");
+function render() {
+  const lines = input.value.split("\n");
+  let parts = [];
+  let width = 0;
+  lines.forEach((line, row) => {
+    let x = 0;
+    for (const c of line) {
+      const glyph = GLYPHS[CHARS[c.codePointAt(0)]];
+      if (!glyph) continue;
+      const dx = x - glyph.l;
+      const dy = row * HEIGHT - TOP;
+      for (const path of glyph.p) {
+        let points = [];
+        for (let i = 0; i < path.length; i += 2) {
+          points.push((path[i] + dx) + "," + (path[i + 1] + dy));
+        }
+        parts.push('<polyline points="' + points.join(" ") + '"/>');
+      }
+      x += glyph.r - glyph.l;
+    }
+    width = Math.max(width, x);
+  });
+  const height = Math.max(lines.length, 1) * HEIGHT;
+  output.innerHTML = '<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 ' + Math.max(width, 1) + ' ' + height +
+    '" height="' + height * 3 + '"><g fill="none" stroke="black" stroke-width="1" stroke-linecap="round" stroke-linejoin="round">' +
+    parts.join("") + '</g></svg>';
+}
+input.addEventListener("input", render);
+render();"#;
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+fn glyph_json(glyph: &HersheyGlyph) -> String {
+    let paths = glyph
+        .paths
+        .iter()
+        .map(|path| {
+            let coordinates = path
+                .iter()
+                .map(|edge| format!("{},{}", edge.x, edge.y))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            format!("[{}]", coordinates)
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        "{{\"l\":{},\"r\":{},\"p\":[{}]}}",
+        glyph.left, glyph.right, paths
+    )
+}
+
+fn glyph_svg(glyph: &HersheyGlyph, top: i32, height: i32) -> String {
+    let width = (glyph.right - glyph.left).max(1);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" height=\"60\">",
+        glyph.left, top, width, height
+    );
+
+    svg.push_str("<g fill=\"none\" stroke=\"black\" stroke-width=\"1\" stroke-linecap=\"round\" stroke-linejoin=\"round\">");
+
+    for path in glyph.paths.iter().filter(|path| !path.is_empty()) {
+        let points = path
+            .iter()
+            .map(|edge| format!("{},{}", edge.x, edge.y))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        svg.push_str(&format!("<polyline points=\"{}\"/>", points));
+    }
+
+    svg.push_str("</g></svg>");
+    svg
+}
+
+impl HersheyFont {
+    pub fn to_html_preview(&self) -> String {
+        let title = self
+            .meta()
+            .and_then(|meta| meta.name)
+            .unwrap_or_else(|| "Hershey font".to_string());
+        let height = font_height(self) as i32;
+
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+
+        html.push_str(&format!("<title>{}</title>\n", escape_html(&title)));
+        html.push_str("<style>body{font-family:sans-serif}figure{display:inline-block;margin:4px;text-align:center}figcaption{font-size:12px;color:#666}</style>\n");
+        html.push_str("</head>\n<body>\n");
+        html.push_str(&format!("<h1>{}</h1>\n", escape_html(&title)));
+
+        if let Some(meta) = self.meta() {
+            for line in meta.lines() {
+                html.push_str(&format!("<p>{}</p>\n", escape_html(&line)));
+            }
+        }
+
+        html.push_str("<textarea id=\"preview-text\" rows=\"3\" cols=\"60\">The quick brown fox jumps over the lazy dog</textarea>\n");
+        html.push_str("<div id=\"preview\"></div>\n<div>\n");
+
+        let mut chars = Vec::new();
+
+        for (i, glyph) in self.glyphs.iter().enumerate() {
+            let mut mapped = self.chars_for_glyph(i);
+
+            mapped.sort_unstable();
+            chars.extend(mapped.iter().map(|c| format!("\"{}\":{}", *c as u32, i)));
+
+            let label = escape_html(&mapped.iter().collect::<String>());
+
+            html.push_str(&format!(
+                "<figure>{}<figcaption>{}</figcaption></figure>\n",
+                glyph_svg(glyph, self.top, height),
+                label
+            ));
+        }
+
+        let glyphs = self
+            .glyphs
+            .iter()
+            .map(glyph_json)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        html.push_str("</div>\n<script>\n");
+        html.push_str(&format!(
+            "const GLYPHS = [{}];\nconst CHARS = {{{}}};\nconst TOP = {};\nconst HEIGHT = {};\n",
+            glyphs,
+            chars.join(","),
+            self.top,
+            height
+        ));
+        html.push_str(SCRIPT);
+        html.push_str("\n</script>\n</body>\n</html>\n");
+        html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapping::CharMapping;
+    use std::collections::HashMap;
+
+    #[test]
+    fn to_html_preview_works() {
+        let font = HersheyFont::new("name: <test>\n  720  1JZ\n  720  3JZRHR\\").unwrap();
+        let html = font.to_html_preview();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>&lt;test&gt;</title>"));
+        assert!(html.contains(
+            "const GLYPHS = [{\"l\":-8,\"r\":8,\"p\":[]},{\"l\":-8,\"r\":8,\"p\":[[0,-10,0,10]]}];"
+        ));
+        assert!(html.contains("<polyline points=\"0,-10 0,10\"/>"));
+        assert!(html.contains("<figcaption>!</figcaption>"));
+        assert!(html.contains("const CHARS = {\"32\":0,\"33\":1};"));
+    }
+
+    #[test]
+    fn to_html_preview_uses_char_mapping() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\")
+            .unwrap()
+            .with_mapping(CharMapping::Custom(HashMap::from([('Ω', 1), ('<', 0)])));
+        let html = font.to_html_preview();
+
+        assert!(html.contains("<figcaption>&lt;</figcaption>"));
+        assert!(html.contains("<figcaption>Ω</figcaption>"));
+        assert!(html.contains("const CHARS = {\"60\":0,\"937\":1};"));
+        assert!(html.contains("GLYPHS[CHARS[c.codePointAt(0)]]"));
+    }
+}
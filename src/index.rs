@@ -0,0 +1,54 @@
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NumberIndex {
+    entries: Vec<(u32, usize)>,
+}
+
+impl NumberIndex {
+    pub fn new(numbers: impl IntoIterator<Item = u32>) -> NumberIndex {
+        let mut entries = numbers
+            .into_iter()
+            .enumerate()
+            .map(|(position, number)| (number, position))
+            .collect::<Vec<_>>();
+
+        entries.sort_by_key(|(number, _)| *number);
+        entries.dedup_by_key(|(number, _)| *number);
+
+        NumberIndex { entries }
+    }
+
+    pub fn get(&self, number: u32) -> Option<usize> {
+        self.entries
+            .binary_search_by_key(&number, |(number, _)| *number)
+            .ok()
+            .map(|i| self.entries[i].1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn heap_size(&self) -> usize {
+        self.entries.capacity() * std::mem::size_of::<(u32, usize)>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_works() {
+        let index = NumberIndex::new([3001, 501, 2273, 501]);
+
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.get(501), Some(1));
+        assert_eq!(index.get(2273), Some(2));
+        assert_eq!(index.get(3001), Some(0));
+        assert_eq!(index.get(502), None);
+    }
+}
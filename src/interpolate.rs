@@ -0,0 +1,138 @@
+use crate::{Edge, HersheyFont, HersheyGlyph};
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyFontInterpolateError {
+    #[error("{0}")]
+    IncompatibleGlyphs(String),
+}
+
+fn mix(a: i32, b: i32, weight: f64) -> i32 {
+    (a as f64 + (b - a) as f64 * weight).round() as i32
+}
+
+impl HersheyGlyph {
+    pub fn interpolate(
+        &self,
+        other: &HersheyGlyph,
+        weight: f64,
+    ) -> Result<HersheyGlyph, HersheyFontInterpolateError> {
+        let compatible = self.paths.len() == other.paths.len()
+            && self
+                .paths
+                .iter()
+                .zip(&other.paths)
+                .all(|(a, b)| a.len() == b.len());
+
+        if !compatible {
+            return Err(HersheyFontInterpolateError::IncompatibleGlyphs(format!(
+                "Glyphs {} and {} have different path or point counts",
+                self.number, other.number
+            )));
+        }
+
+        let paths = self
+            .paths
+            .iter()
+            .zip(&other.paths)
+            .map(|(a, b)| {
+                a.iter()
+                    .zip(b)
+                    .map(|(a, b)| Edge {
+                        x: mix(a.x, b.x, weight),
+                        y: mix(a.y, b.y, weight),
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut glyph = HersheyGlyph::new(
+            mix(self.left, other.left, weight),
+            mix(self.right, other.right, weight),
+            paths,
+        );
+
+        glyph.number = self.number;
+        Ok(glyph)
+    }
+}
+
+impl HersheyFont {
+    pub fn interpolate(
+        &self,
+        other: &HersheyFont,
+        weight: f64,
+    ) -> Result<HersheyFont, HersheyFontInterpolateError> {
+        if self.glyphs.len() != other.glyphs.len() {
+            return Err(HersheyFontInterpolateError::IncompatibleGlyphs(format!(
+                "Fonts have {} and {} glyphs",
+                self.glyphs.len(),
+                other.glyphs.len()
+            )));
+        }
+
+        let glyphs = self
+            .glyphs
+            .iter()
+            .zip(&other.glyphs)
+            .enumerate()
+            .map(|(i, (a, b))| {
+                a.interpolate(b, weight).map_err(|_| {
+                    HersheyFontInterpolateError::IncompatibleGlyphs(format!(
+                        "Glyph {} has different path or point counts in the two fonts",
+                        i
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(self.with_glyphs(glyphs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+
+    #[test]
+    fn interpolate_works() {
+        let light = HersheyFont::new("  699  1JZ\n  714  3MWRHR\\").unwrap();
+        let bold = HersheyFont::new("  699  1HZ\n 2714  3KYPHT\\").unwrap();
+        let blended = light.interpolate(&bold, 0.5).unwrap();
+        let glyph = blended.get_glyph('!').unwrap();
+
+        assert_eq!((glyph.left, glyph.right), (-6, 6));
+        assert_eq!(
+            glyph.paths,
+            vec![vec![Edge { x: -1, y: -10 }, Edge { x: 1, y: 10 }]]
+        );
+        assert_eq!(glyph.number, 714);
+        assert_eq!(blended.get_glyph(' ').unwrap().left, -9);
+    }
+
+    #[test]
+    fn interpolate_keeps_font_settings() {
+        let mut light = HersheyFont::new("name: Light\n  699  1JZ\n  714  3MWRHR\\").unwrap();
+        let bold = HersheyFont::new("  699  1HZ\n 2714  3KYPHT\\").unwrap();
+        light.coordinate_system = CoordinateSystem::TopLeft;
+
+        let blended = light.interpolate(&bold, 0.5).unwrap();
+
+        assert_eq!(blended.coordinate_system, CoordinateSystem::TopLeft);
+        assert_eq!(blended.meta(), light.meta());
+        assert_eq!(blended.metadata, light.metadata);
+    }
+
+    #[test]
+    fn interpolate_returns_error_for_incompatible_fonts() {
+        let light = HersheyFont::new("  714  3MWRHR\\").unwrap();
+        let other = HersheyFont::new("  714  4MWRHR\\RR").unwrap();
+
+        assert!(matches!(
+            light.interpolate(&other, 0.5),
+            Err(HersheyFontInterpolateError::IncompatibleGlyphs(_))
+        ));
+        assert!(light
+            .interpolate(&HersheyFont::new("  699  1JZ\n  699  1JZ").unwrap(), 0.5)
+            .is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{HersheyFont, HersheyGlyph};
+use std::ops::Index;
+
+impl HersheyFont {
+    pub fn glyphs(&self) -> std::slice::Iter<'_, HersheyGlyph> {
+        self.glyphs.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&HersheyGlyph> {
+        self.glyphs.get(index)
+    }
+}
+
+impl Index<usize> for HersheyFont {
+    type Output = HersheyGlyph;
+
+    fn index(&self, index: usize) -> &HersheyGlyph {
+        &self.glyphs[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a HersheyFont {
+    type Item = &'a HersheyGlyph;
+    type IntoIter = std::slice::Iter<'a, HersheyGlyph>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.glyphs.iter()
+    }
+}
+
+impl IntoIterator for HersheyFont {
+    type Item = HersheyGlyph;
+    type IntoIter = std::vec::IntoIter<HersheyGlyph>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.glyphs.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iteration_works() {
+        let font = HersheyFont::new("  699  1JZ\n  714  3JZRHR\\").unwrap();
+
+        assert_eq!(font.len(), 2);
+        assert!(!font.is_empty());
+        assert_eq!(font[1].number, 714);
+        assert!(font.get(2).is_none());
+        assert_eq!(
+            font.glyphs().map(|glyph| glyph.number).collect::<Vec<_>>(),
+            vec![699, 714]
+        );
+        assert_eq!((&font).into_iter().count(), 2);
+        assert_eq!(font.into_iter().last().unwrap().paths.len(), 1);
+    }
+}
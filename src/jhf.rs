@@ -0,0 +1,101 @@
+use crate::{HersheyFont, HersheyGlyph};
+
+#[derive(thiserror::Error, Debug)]
+pub enum JhfWriteError {
+    #[error("{0}")]
+    OutOfRange(String),
+}
+
+fn int_to_char(value: i32) -> Result<char, JhfWriteError> {
+    u8::try_from(value + 'R' as i32)
+        .ok()
+        .filter(|byte| (b' '..=b'~').contains(byte))
+        .map(char::from)
+        .ok_or(JhfWriteError::OutOfRange(format!(
+            "Coordinate {} cannot be encoded",
+            value
+        )))
+}
+
+impl HersheyGlyph {
+    pub fn to_jhf_line(&self) -> Result<String, JhfWriteError> {
+        let mut pairs = String::new();
+
+        for (i, path) in self
+            .paths
+            .iter()
+            .filter(|path| !path.is_empty())
+            .enumerate()
+        {
+            if i > 0 {
+                pairs.push_str(" R");
+            }
+
+            for edge in path {
+                if edge.x == ' ' as i32 - 'R' as i32 && edge.y == 0 {
+                    return Err(JhfWriteError::OutOfRange(format!(
+                        "Vertex ({}, {}) collides with the pen-up marker",
+                        edge.x, edge.y
+                    )));
+                }
+
+                pairs.push(int_to_char(edge.x)?);
+                pairs.push(int_to_char(edge.y)?);
+            }
+        }
+
+        let count = pairs.len() / 2 + 1;
+
+        if count > 999 || self.number > 99999 {
+            return Err(JhfWriteError::OutOfRange(format!(
+                "Glyph {} has too many vertices to encode",
+                self.number
+            )));
+        }
+
+        Ok(format!(
+            "{:>5}{:>3}{}{}{}",
+            self.number,
+            count,
+            int_to_char(self.left)?,
+            int_to_char(self.right)?,
+            pairs
+        ))
+    }
+}
+
+impl HersheyFont {
+    pub fn to_jhf(&self) -> Result<String, JhfWriteError> {
+        let mut output = String::new();
+
+        for glyph in &self.glyphs {
+            output.push_str(&glyph.to_jhf_line()?);
+            output.push('\n');
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Edge;
+
+    #[test]
+    fn to_jhf_round_trips() {
+        let data = "  699  1JZ\n  714  9MWRFRT RRYQZR[SZRY\n 2273  3G][BIb\n";
+        let font = HersheyFont::new(data).unwrap();
+
+        assert_eq!(font.to_jhf().unwrap(), data);
+    }
+
+    #[test]
+    fn to_jhf_line_returns_error_for_out_of_range_coordinates() {
+        let glyph = HersheyGlyph::new(0, 0, vec![vec![Edge { x: 60, y: 0 }]]);
+        let marker = HersheyGlyph::new(0, 0, vec![vec![Edge { x: -50, y: 0 }]]);
+
+        assert!(glyph.to_jhf_line().is_err());
+        assert!(marker.to_jhf_line().is_err());
+    }
+}
@@ -0,0 +1,587 @@
+use crate::hmp::HersheyMap;
+use crate::mapping::CharMapping;
+use crate::{Edge, HersheyFont, HersheyGlyph};
+use std::collections::{BTreeMap, HashMap};
+
+const MAX_DEPTH: usize = 64;
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyFontFromJsonError {
+    #[error("{0}")]
+    InvalidJson(String),
+    #[error("{0}")]
+    InvalidFont(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(entries) => entries
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> HersheyFontFromJsonError {
+        HersheyFontFromJsonError::InvalidJson(format!(
+            "Error parsing JSON at byte {}: {}",
+            self.position, message
+        ))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .data
+            .get(self.position)
+            .is_some_and(|b| b.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), HersheyFontFromJsonError> {
+        self.skip_whitespace();
+
+        if self.data.get(self.position) == Some(&byte) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", byte as char)))
+        }
+    }
+
+    fn literal(
+        &mut self,
+        text: &str,
+        value: JsonValue,
+    ) -> Result<JsonValue, HersheyFontFromJsonError> {
+        if self.data[self.position..].starts_with(text.as_bytes()) {
+            self.position += text.len();
+            Ok(value)
+        } else {
+            Err(self.error("unexpected token"))
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, HersheyFontFromJsonError> {
+        self.skip_whitespace();
+
+        match self.data.get(self.position) {
+            Some(b'{' | b'[') if self.depth >= MAX_DEPTH => Err(self.error("nesting too deep")),
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b't') => self.literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.literal("false", JsonValue::Bool(false)),
+            Some(b'n') => self.literal("null", JsonValue::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn separated<T>(
+        &mut self,
+        close: u8,
+        mut item: impl FnMut(&mut Self) -> Result<T, HersheyFontFromJsonError>,
+    ) -> Result<Vec<T>, HersheyFontFromJsonError> {
+        let mut items = Vec::new();
+
+        self.position += 1;
+        self.depth += 1;
+        self.skip_whitespace();
+
+        if self.data.get(self.position) == Some(&close) {
+            self.position += 1;
+            self.depth -= 1;
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+            self.skip_whitespace();
+
+            match self.data.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b) if *b == close => {
+                    self.position += 1;
+                    self.depth -= 1;
+                    return Ok(items);
+                }
+                _ => return Err(self.error(&format!("expected ',' or '{}'", close as char))),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, HersheyFontFromJsonError> {
+        self.separated(b'}', |parser| {
+            parser.skip_whitespace();
+
+            if parser.data.get(parser.position) != Some(&b'"') {
+                return Err(parser.error("expected string key"));
+            }
+
+            let key = parser.string()?;
+            parser.expect(b':')?;
+
+            Ok((key, parser.value()?))
+        })
+        .map(JsonValue::Object)
+    }
+
+    fn array(&mut self) -> Result<JsonValue, HersheyFontFromJsonError> {
+        self.separated(b']', Parser::value).map(JsonValue::Array)
+    }
+
+    fn string(&mut self) -> Result<String, HersheyFontFromJsonError> {
+        let mut bytes = Vec::new();
+
+        self.position += 1;
+
+        loop {
+            let Some(&byte) = self.data.get(self.position) else {
+                return Err(self.error("unterminated string"));
+            };
+            self.position += 1;
+
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let Some(&escape) = self.data.get(self.position) else {
+                        return Err(self.error("unterminated string"));
+                    };
+                    self.position += 1;
+
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(self.error("invalid escape")),
+                    };
+
+                    bytes.extend(c.to_string().as_bytes());
+                }
+                _ => bytes.push(byte),
+            }
+        }
+
+        String::from_utf8(bytes).map_err(|_| self.error("invalid UTF-8 in string"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, HersheyFontFromJsonError> {
+        let digits = self
+            .data
+            .get(self.position..self.position + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+
+        self.position += 4;
+        Ok(digits)
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, HersheyFontFromJsonError> {
+        let high = self.hex4()?;
+        let code =
+            if (0xd800..0xdc00).contains(&high) && self.data[self.position..].starts_with(b"\\u") {
+                self.position += 2;
+                let low = self.hex4()?;
+
+                0x10000 + ((high - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff)
+            } else {
+                high
+            };
+
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn number(&mut self) -> Result<JsonValue, HersheyFontFromJsonError> {
+        let start = self.position;
+
+        while self
+            .data
+            .get(self.position)
+            .is_some_and(|b| b.is_ascii_digit() || b"+-.eE".contains(b))
+        {
+            self.position += 1;
+        }
+
+        std::str::from_utf8(&self.data[start..self.position])
+            .ok()
+            .and_then(|text| text.parse::<f64>().ok())
+            .map(JsonValue::Number)
+            .ok_or_else(|| self.error("invalid number"))
+    }
+}
+
+fn parse_json(data: &str) -> Result<JsonValue, HersheyFontFromJsonError> {
+    let mut parser = Parser {
+        data: data.as_bytes(),
+        position: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+
+    parser.skip_whitespace();
+
+    if parser.position < parser.data.len() {
+        return Err(parser.error("trailing characters"));
+    }
+
+    Ok(value)
+}
+
+fn escape_json(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            c if (c as u32) < 0x20 => format!("\\u{:04x}", c as u32),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+fn integer(
+    value: Option<&JsonValue>,
+    field: &str,
+    glyph: usize,
+) -> Result<i32, HersheyFontFromJsonError> {
+    match value {
+        Some(JsonValue::Number(n)) if n.fract() == 0.0 && n.abs() <= i32::MAX as f64 => {
+            Ok(*n as i32)
+        }
+        _ => Err(HersheyFontFromJsonError::InvalidFont(format!(
+            "Glyph {} has a missing or invalid {}",
+            glyph, field
+        ))),
+    }
+}
+
+fn json_to_glyph(
+    value: &JsonValue,
+    index: usize,
+) -> Result<HersheyGlyph, HersheyFontFromJsonError> {
+    let invalid_paths =
+        || HersheyFontFromJsonError::InvalidFont(format!("Glyph {} has invalid paths", index));
+
+    let paths = match value.get("paths") {
+        Some(JsonValue::Array(paths)) => paths
+            .iter()
+            .map(|path| match path {
+                JsonValue::Array(points) => points
+                    .iter()
+                    .map(|point| match point {
+                        JsonValue::Array(xy) if xy.len() == 2 => Ok(Edge {
+                            x: integer(Some(&xy[0]), "x coordinate", index)?,
+                            y: integer(Some(&xy[1]), "y coordinate", index)?,
+                        }),
+                        _ => Err(invalid_paths()),
+                    })
+                    .collect::<Result<Vec<_>, _>>(),
+                _ => Err(invalid_paths()),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Vec::new(),
+        Some(_) => return Err(invalid_paths()),
+    };
+
+    let mut glyph = HersheyGlyph::new(
+        integer(value.get("left"), "left", index)?,
+        integer(value.get("right"), "right", index)?,
+        paths,
+    );
+
+    if let Some(number) = value.get("number") {
+        glyph.number = u32::try_from(integer(Some(number), "number", index)?).map_err(|_| {
+            HersheyFontFromJsonError::InvalidFont(format!("Glyph {} has a negative number", index))
+        })?;
+    }
+
+    Ok(glyph)
+}
+
+fn index(value: &JsonValue, field: &str) -> Result<usize, HersheyFontFromJsonError> {
+    match value {
+        JsonValue::Number(n) if n.fract() == 0.0 && *n >= 0.0 && *n <= u32::MAX as f64 => {
+            Ok(*n as usize)
+        }
+        _ => Err(HersheyFontFromJsonError::InvalidFont(format!(
+            "Mapping has a missing or invalid {}",
+            field
+        ))),
+    }
+}
+
+fn json_to_mapping(value: Option<&JsonValue>) -> Result<CharMapping, HersheyFontFromJsonError> {
+    let invalid = || HersheyFontFromJsonError::InvalidFont("Mapping is invalid".to_string());
+
+    let Some(value) = value else {
+        return Ok(CharMapping::AsciiOffset);
+    };
+
+    match (value.get("type"), value.get("chars"), value.get("numbers")) {
+        (Some(JsonValue::String(kind)), Some(JsonValue::Object(chars)), None)
+            if kind == "custom" =>
+        {
+            chars
+                .iter()
+                .map(|(key, value)| {
+                    let mut key_chars = key.chars();
+
+                    match (key_chars.next(), key_chars.next()) {
+                        (Some(c), None) => Ok((c, index(value, "glyph index")?)),
+                        _ => Err(invalid()),
+                    }
+                })
+                .collect::<Result<HashMap<_, _>, _>>()
+                .map(CharMapping::Custom)
+        }
+        (Some(JsonValue::String(kind)), None, Some(JsonValue::Array(numbers))) if kind == "hmp" => {
+            numbers
+                .iter()
+                .map(|number| Ok(index(number, "glyph number")? as u32))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|numbers| CharMapping::HersheyMapFile(HersheyMap { numbers }))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn mapping_to_json(mapping: &CharMapping) -> Option<String> {
+    match mapping {
+        CharMapping::AsciiOffset => None,
+        CharMapping::Custom(chars) => {
+            let mut chars = chars.iter().collect::<Vec<_>>();
+            chars.sort_unstable();
+
+            let chars = chars
+                .iter()
+                .map(|(c, index)| format!("\"{}\":{}", escape_json(&c.to_string()), index))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            Some(format!("{{\"type\":\"custom\",\"chars\":{{{}}}}}", chars))
+        }
+        CharMapping::HersheyMapFile(map) => {
+            let numbers = map
+                .numbers
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+
+            Some(format!("{{\"type\":\"hmp\",\"numbers\":[{}]}}", numbers))
+        }
+    }
+}
+
+impl HersheyFont {
+    pub fn from_json(data: &str) -> Result<HersheyFont, HersheyFontFromJsonError> {
+        let root = parse_json(data)?;
+
+        let metadata = match root.get("metadata") {
+            Some(JsonValue::Object(entries)) => entries
+                .iter()
+                .map(|(key, value)| match value {
+                    JsonValue::String(value) => Ok((key.clone(), value.clone())),
+                    _ => Err(HersheyFontFromJsonError::InvalidFont(format!(
+                        "Metadata value for {} is not a string",
+                        key
+                    ))),
+                })
+                .collect::<Result<BTreeMap<_, _>, _>>()?,
+            None => BTreeMap::new(),
+            Some(_) => {
+                return Err(HersheyFontFromJsonError::InvalidFont(
+                    "Metadata is not an object".to_string(),
+                ))
+            }
+        };
+
+        let Some(JsonValue::Array(glyphs)) = root.get("glyphs") else {
+            return Err(HersheyFontFromJsonError::InvalidFont(
+                "Font has no glyphs array".to_string(),
+            ));
+        };
+
+        let glyphs = glyphs
+            .iter()
+            .enumerate()
+            .map(|(i, glyph)| json_to_glyph(glyph, i))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(HersheyFont::from_parts(metadata, glyphs)
+            .with_mapping(json_to_mapping(root.get("mapping"))?))
+    }
+
+    pub fn to_json(&self) -> String {
+        let metadata = self
+            .metadata
+            .iter()
+            .map(|(key, value)| format!("\"{}\":\"{}\"", escape_json(key), escape_json(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let glyphs = self
+            .glyphs
+            .iter()
+            .map(|glyph| {
+                let paths = glyph
+                    .paths
+                    .iter()
+                    .map(|path| {
+                        let points = path
+                            .iter()
+                            .map(|edge| format!("[{},{}]", edge.x, edge.y))
+                            .collect::<Vec<_>>()
+                            .join(",");
+
+                        format!("[{}]", points)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                format!(
+                    "{{\"number\":{},\"left\":{},\"right\":{},\"paths\":[{}]}}",
+                    glyph.number, glyph.left, glyph.right, paths
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        let mapping = mapping_to_json(&self.mapping)
+            .map_or(String::new(), |mapping| format!("\"mapping\":{},", mapping));
+
+        format!(
+            "{{\"metadata\":{{{}}},{}\"glyphs\":[\n{}\n]}}\n",
+            metadata, mapping, glyphs
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_json_works() {
+        let font = HersheyFont::new("name: futural\n  699  1JZ\n  714  3MWRHR\\").unwrap();
+
+        assert_eq!(
+            font.to_json(),
+            "{\"metadata\":{\"name\":\"futural\"},\"glyphs\":[\n{\"number\":699,\"left\":-8,\"right\":8,\"paths\":[]},\n{\"number\":714,\"left\":-5,\"right\":5,\"paths\":[[[0,-10],[0,10]]]}\n]}\n"
+        );
+    }
+
+    #[test]
+    fn json_round_trip_is_lossless() {
+        let font =
+            HersheyFont::new("name: \"quoted\" \\ name\n  699  1JZ\n  714  5MWRHR\\ RRR").unwrap();
+        let parsed = HersheyFont::from_json(&font.to_json()).unwrap();
+
+        assert_eq!(parsed.metadata, font.metadata);
+        assert_eq!(parsed.to_json(), font.to_json());
+        for (parsed, glyph) in parsed.glyphs().zip(font.glyphs()) {
+            assert_eq!(
+                (parsed.number, parsed.left, parsed.right, &parsed.paths),
+                (glyph.number, glyph.left, glyph.right, &glyph.paths)
+            );
+        }
+        assert_eq!(parsed.get_glyph_by_number(714).unwrap().paths.len(), 2);
+    }
+
+    #[test]
+    fn json_round_trip_keeps_mapping_and_meta() {
+        let data = "name: futural\nlicense: public domain\n  699  1JZ\n  714  3MWRHR\\";
+        let custom = HersheyFont::new(data)
+            .unwrap()
+            .with_mapping(CharMapping::Custom(HashMap::from([('é', 1), ('"', 0)])));
+        let mapped = HersheyFont::new(data)
+            .unwrap()
+            .with_mapping(CharMapping::HersheyMapFile(HersheyMap {
+                numbers: vec![714, 699],
+            }));
+
+        for font in [custom, mapped] {
+            let parsed = HersheyFont::from_json(&font.to_json()).unwrap();
+
+            assert_eq!(parsed, font);
+            assert_eq!(parsed.meta(), font.meta());
+        }
+    }
+
+    #[test]
+    fn from_json_accepts_whitespace_and_escapes() {
+        let font = HersheyFont::from_json(
+            r#" { "metadata" : { "name" : "café\n" } ,
+                  "glyphs" : [ { "left" : -3 , "right" : 3 , "paths" : [ [ [ 0 , -1 ] , [ 0 , 1 ] ] ] } ] } "#,
+        )
+        .unwrap();
+
+        assert_eq!(font.metadata["name"], "café\n");
+        assert_eq!(
+            font.get_glyph(' ').unwrap().paths[0][1],
+            Edge { x: 0, y: 1 }
+        );
+    }
+
+    #[test]
+    fn from_json_returns_errors() {
+        assert!(matches!(
+            HersheyFont::from_json("{\"glyphs\": [}"),
+            Err(HersheyFontFromJsonError::InvalidJson(_))
+        ));
+        assert!(matches!(
+            HersheyFont::from_json("{\"glyphs\": [{\"left\": 1.5, \"right\": 2}]}"),
+            Err(HersheyFontFromJsonError::InvalidFont(_))
+        ));
+        assert!(matches!(
+            HersheyFont::from_json("{}"),
+            Err(HersheyFontFromJsonError::InvalidFont(_))
+        ));
+        assert!(matches!(
+            HersheyFont::from_json("{\"glyphs\": [{\"number\": -1, \"left\": 0, \"right\": 0}]}"),
+            Err(HersheyFontFromJsonError::InvalidFont(_))
+        ));
+    }
+
+    #[test]
+    fn from_json_limits_nesting_depth() {
+        let data = format!("{{\"glyphs\":{}", "[".repeat(200_000));
+
+        assert!(matches!(
+            HersheyFont::from_json(&data),
+            Err(HersheyFontFromJsonError::InvalidJson(_))
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Kerning {
+    pairs: HashMap<(char, char), i32>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum KerningParseError {
+    #[error("{0}")]
+    InvalidPair(String),
+}
+
+impl Kerning {
+    pub fn new() -> Kerning {
+        Kerning::default()
+    }
+
+    pub fn set(&mut self, left: char, right: char, adjustment: i32) {
+        self.pairs.insert((left, right), adjustment);
+    }
+
+    pub fn get(&self, left: char, right: char) -> i32 {
+        self.pairs.get(&(left, right)).copied().unwrap_or(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    pub fn parse(data: &str) -> Result<Kerning, KerningParseError> {
+        let mut kerning = Kerning::new();
+
+        for (i, line) in data.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                KerningParseError::InvalidPair(format!("Invalid kerning pair on line {}", i + 1))
+            };
+            let (pair, adjustment) = line.rsplit_once(char::is_whitespace).ok_or_else(invalid)?;
+            let mut chars = pair.trim_end().chars();
+            let (Some(left), Some(right), None) = (chars.next(), chars.next(), chars.next()) else {
+                return Err(invalid());
+            };
+
+            kerning.set(left, right, adjustment.parse().map_err(|_| invalid())?);
+        }
+
+        Ok(kerning)
+    }
+}
+
+impl std::str::FromStr for Kerning {
+    type Err = KerningParseError;
+
+    fn from_str(data: &str) -> Result<Kerning, KerningParseError> {
+        Kerning::parse(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        let kerning = Kerning::parse("# pairs\nAV -3\nTo -2\n\n").unwrap();
+
+        assert_eq!(kerning.len(), 2);
+        assert_eq!(kerning.get('A', 'V'), -3);
+        assert_eq!(kerning.get('T', 'o'), -2);
+        assert_eq!(kerning.get('V', 'A'), 0);
+        assert!("AVA 1".parse::<Kerning>().is_err());
+        assert!("AV x".parse::<Kerning>().is_err());
+    }
+}
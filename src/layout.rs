@@ -0,0 +1,639 @@
+use crate::bounds::{edges_bounds, Bounds};
+use crate::kerning::Kerning;
+use crate::text::{font_height, Anchor};
+use crate::{Edge, HersheyFont};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionedGlyph {
+    pub character: char,
+    pub index: usize,
+    pub x: i32,
+    pub y: i32,
+    pub advance: i32,
+    pub paths: Vec<Vec<Edge>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Direction {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+    TopToBottom,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutOptions {
+    pub max_width: Option<i32>,
+    pub line_spacing: f64,
+    pub alignment: Alignment,
+    pub tracking: i32,
+    pub kerning: Option<Kerning>,
+    pub direction: Direction,
+    pub anchor: Option<Anchor>,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> LayoutOptions {
+        LayoutOptions {
+            max_width: None,
+            line_spacing: 1.0,
+            alignment: Alignment::Left,
+            tracking: 0,
+            kerning: None,
+            direction: Direction::LeftToRight,
+            anchor: None,
+        }
+    }
+}
+
+impl LayoutOptions {
+    fn spacing(&self, previous: Option<char>, next: char) -> i32 {
+        previous.map_or(0, |previous| {
+            self.tracking
+                + self
+                    .kerning
+                    .as_ref()
+                    .map_or(0, |kerning| kerning.get(previous, next))
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutLine {
+    pub glyphs: std::ops::Range<usize>,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextLayout {
+    pub glyphs: Vec<PositionedGlyph>,
+    pub lines: Vec<LayoutLine>,
+    pub width: i32,
+    pub height: i32,
+    pub direction: Direction,
+}
+
+impl TextLayout {
+    pub fn paths(&self) -> Vec<Vec<Edge>> {
+        self.glyphs
+            .iter()
+            .flat_map(|glyph| glyph.paths.iter().cloned())
+            .collect()
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = (Edge, Edge)> + '_ {
+        self.glyphs
+            .iter()
+            .flat_map(|glyph| glyph.paths.iter())
+            .flat_map(|path| path.windows(2).map(|pair| (pair[0], pair[1])))
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        edges_bounds(
+            self.glyphs
+                .iter()
+                .flat_map(|glyph| glyph.paths.iter().flatten()),
+        )
+    }
+
+    pub fn positions(&self) -> Vec<(char, i32, i32)> {
+        self.glyphs
+            .iter()
+            .map(|glyph| (glyph.character, glyph.x, glyph.y))
+            .collect()
+    }
+
+    pub fn line_bounds(&self, line: usize) -> Option<Bounds> {
+        edges_bounds(
+            self.line_glyphs(line)
+                .iter()
+                .flat_map(|glyph| glyph.paths.iter().flatten()),
+        )
+    }
+
+    pub fn line_glyphs(&self, line: usize) -> &[PositionedGlyph] {
+        self.lines
+            .get(line)
+            .map_or(&[], |line| &self.glyphs[line.glyphs.clone()])
+    }
+}
+
+impl HersheyFont {
+    pub(crate) fn space_advance(&self) -> i32 {
+        self.get_glyph_checked(' ')
+            .map(|glyph| glyph.right - glyph.left)
+            .unwrap_or_else(|| (font_height(self) as i32 / 2).max(1))
+    }
+
+    pub(crate) fn glyph_advance(&self, character: char) -> Option<i32> {
+        match self.get_glyph_checked(character) {
+            Some(glyph) => Some(glyph.right - glyph.left),
+            None => (character == ' ').then(|| self.space_advance()),
+        }
+    }
+
+    pub(crate) fn place_glyph(&self, character: char, x: i32, y: i32) -> Option<PositionedGlyph> {
+        if character == ' ' && self.get_glyph_checked(' ').is_none() {
+            return Some(PositionedGlyph {
+                character,
+                index: 0,
+                x,
+                y,
+                advance: self.space_advance(),
+                paths: Vec::new(),
+            });
+        }
+
+        let glyph = self.get_glyph_checked(character)?;
+        let dx = x - glyph.left;
+
+        Some(PositionedGlyph {
+            character,
+            index: 0,
+            x,
+            y,
+            advance: glyph.right - glyph.left,
+            paths: glyph
+                .paths
+                .iter()
+                .map(|path| {
+                    path.iter()
+                        .map(|edge| Edge {
+                            x: edge.x + dx,
+                            y: edge.y + y,
+                        })
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+
+    pub fn measure_text(&self, text: &str) -> i32 {
+        self.measure_text_with_options(text, &LayoutOptions::default())
+    }
+
+    pub fn measure_text_with_options(&self, text: &str, options: &LayoutOptions) -> i32 {
+        Typesetter::new(self, &|_| Some(self), options, text).measure(text)
+    }
+
+    pub fn layout_text(&self, text: &str) -> TextLayout {
+        self.layout_text_with_options(text, &LayoutOptions::default())
+    }
+
+    pub fn layout_text_with_options(&self, text: &str, options: &LayoutOptions) -> TextLayout {
+        self.layout_text_with_fonts(&|_| Some(self), text, options)
+    }
+
+    pub(crate) fn layout_text_with_fonts<'a>(
+        &'a self,
+        fonts: &dyn Fn(char) -> Option<&'a HersheyFont>,
+        text: &str,
+        options: &LayoutOptions,
+    ) -> TextLayout {
+        Typesetter::new(self, fonts, options, text).layout(text)
+    }
+}
+
+struct Typesetter<'a, 'f> {
+    primary: &'a HersheyFont,
+    fonts: &'f dyn Fn(char) -> Option<&'a HersheyFont>,
+    options: &'f LayoutOptions,
+    em: i32,
+}
+
+impl<'a, 'f> Typesetter<'a, 'f> {
+    fn new(
+        primary: &'a HersheyFont,
+        fonts: &'f dyn Fn(char) -> Option<&'a HersheyFont>,
+        options: &'f LayoutOptions,
+        text: &str,
+    ) -> Typesetter<'a, 'f> {
+        let em = text
+            .chars()
+            .filter_map(fonts)
+            .map(font_height)
+            .fold(font_height(primary), f64::max);
+
+        Typesetter {
+            primary,
+            fonts,
+            options,
+            em: em as i32,
+        }
+    }
+
+    fn vertical(&self) -> bool {
+        self.options.direction == Direction::TopToBottom
+    }
+
+    fn resolve(&self, character: char) -> Option<(&'a HersheyFont, i32)> {
+        let font = (self.fonts)(character).or((character == ' ').then_some(self.primary))?;
+
+        Some((font, font.glyph_advance(character)?))
+    }
+
+    fn advance(&self, width: i32) -> i32 {
+        if self.vertical() {
+            self.em
+        } else {
+            width
+        }
+    }
+
+    fn measure(&self, text: &str) -> i32 {
+        let mut length = 0;
+        let mut previous = None;
+
+        for character in text.chars() {
+            if let Some((_, width)) = self.resolve(character) {
+                length += self.options.spacing(previous, character) + self.advance(width);
+                previous = Some(character);
+            }
+        }
+
+        length
+    }
+
+    fn wrap_paragraph<'t>(&self, paragraph: &'t str) -> Vec<(usize, &'t str)> {
+        let Some(max_width) = self.options.max_width else {
+            return vec![(0, paragraph)];
+        };
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut end = 0;
+
+        for (offset, word) in paragraph.split(' ').scan(0, |offset, word| {
+            let start = *offset;
+            *offset += word.len() + 1;
+            Some((start, word))
+        }) {
+            let word_end = offset + word.len();
+
+            if end > start && self.measure(&paragraph[start..word_end]) > max_width {
+                lines.push((start, &paragraph[start..end]));
+                start = offset;
+            }
+
+            end = word_end;
+        }
+
+        lines.push((start, &paragraph[start..end]));
+        lines
+    }
+
+    fn layout(&self, text: &str) -> TextLayout {
+        let line_height = (self.em as f64 * self.options.line_spacing).round() as i32;
+        let lines = text
+            .split('\n')
+            .scan(0, |offset, paragraph| {
+                let start = *offset;
+                *offset += paragraph.len() + 1;
+                Some((start, paragraph))
+            })
+            .flat_map(|(start, paragraph)| {
+                self.wrap_paragraph(paragraph)
+                    .into_iter()
+                    .map(move |(offset, line)| (start + offset, line))
+            })
+            .collect::<Vec<_>>();
+        let lengths = lines
+            .iter()
+            .map(|(_, line)| self.measure(line))
+            .collect::<Vec<_>>();
+        let longest = lengths.iter().copied().max().unwrap_or(0);
+        let extent = self.options.max_width.unwrap_or(longest);
+        let across = (lines.len() as i32 - 1) * line_height + self.em;
+        let (width, height) = if self.vertical() {
+            (across, extent.max(longest))
+        } else {
+            (extent.max(longest), across)
+        };
+
+        let mut layout = TextLayout {
+            width,
+            height,
+            direction: self.options.direction,
+            ..TextLayout::default()
+        };
+
+        for (i, ((offset, line), length)) in lines.iter().zip(lengths).enumerate() {
+            let start = match self.options.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => (extent - length) / 2,
+                Alignment::Right => extent - length,
+            };
+            let cross = i as i32 * line_height;
+            let (x, y) = if self.vertical() {
+                (cross, start)
+            } else {
+                (start, cross)
+            };
+            let first = layout.glyphs.len();
+            let first_index = text[..*offset].chars().count();
+            let mut position = start;
+            let mut previous = None;
+
+            for (index, character) in line.chars().enumerate() {
+                let Some((font, width)) = self.resolve(character) else {
+                    continue;
+                };
+
+                position += self.options.spacing(previous, character);
+
+                let placed = if self.vertical() {
+                    font.place_glyph(character, cross + (self.em - width) / 2, position)
+                } else {
+                    font.place_glyph(character, position, cross)
+                };
+                let advance = self.advance(width);
+
+                layout.glyphs.extend(placed.map(|glyph| PositionedGlyph {
+                    index: first_index + index,
+                    advance,
+                    ..glyph
+                }));
+                position += advance;
+                previous = Some(character);
+            }
+
+            layout.lines.push(LayoutLine {
+                glyphs: first..layout.glyphs.len(),
+                x,
+                y,
+                width: length,
+            });
+        }
+
+        if self.options.direction == Direction::RightToLeft {
+            layout.mirror_lines();
+        }
+
+        layout.convert_coordinates(self.primary);
+
+        if let Some(anchor) = self.options.anchor {
+            layout.anchor(self.primary, anchor);
+        }
+
+        layout
+    }
+}
+
+impl TextLayout {
+    fn anchor(&mut self, font: &HersheyFont, anchor: Anchor) {
+        let (left, top, right, bottom) = self.bounds().map_or((0.0, 0.0, 0.0, 0.0), |bounds| {
+            (bounds.left, bounds.top, bounds.right, bounds.bottom)
+        });
+        let (sign, offset) = font.y_transform();
+        let baseline = sign * font.baseline() + offset;
+        let center_x = -(left + right) / 2.0;
+        let (dx, dy) = match anchor {
+            Anchor::BaselineStart => (0.0, -baseline as f64),
+            Anchor::TopLeft => (-left, -top),
+            Anchor::Center => (center_x, -(top + bottom) / 2.0),
+            Anchor::CapHeightCenter => {
+                let cap_top = font
+                    .get_glyph_checked('H')
+                    .filter(|glyph| !glyph.paths.is_empty())
+                    .map_or(font.top, |glyph| glyph.top);
+
+                (center_x, -(sign * cap_top + offset + baseline) as f64 / 2.0)
+            }
+        };
+
+        self.translate(dx.round() as i32, dy.round() as i32);
+    }
+
+    fn translate(&mut self, dx: i32, dy: i32) {
+        for glyph in &mut self.glyphs {
+            glyph.x += dx;
+            glyph.y += dy;
+            glyph.paths.iter_mut().flatten().for_each(|edge| {
+                edge.x += dx;
+                edge.y += dy;
+            });
+        }
+
+        for line in &mut self.lines {
+            line.x += dx;
+            line.y += dy;
+        }
+    }
+
+    fn mirror_lines(&mut self) {
+        for line in &self.lines {
+            for glyph in &mut self.glyphs[line.glyphs.clone()] {
+                let x = 2 * line.x + line.width - glyph.x - glyph.advance;
+                let dx = x - glyph.x;
+
+                glyph.x = x;
+                glyph
+                    .paths
+                    .iter_mut()
+                    .flatten()
+                    .for_each(|edge| edge.x += dx);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_text_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text("! !\u{7f}");
+
+        assert_eq!(layout.width, 10 + 16 + 10);
+        assert_eq!(
+            layout.positions(),
+            vec![('!', 0, 0), (' ', 10, 0), ('!', 26, 0)]
+        );
+        assert_eq!(
+            layout.paths(),
+            vec![
+                vec![Edge { x: 5, y: -10 }, Edge { x: 5, y: 10 }],
+                vec![Edge { x: 31, y: -10 }, Edge { x: 31, y: 10 }],
+            ]
+        );
+        assert_eq!(layout.bounds().unwrap().width(), 26.0);
+    }
+
+    #[test]
+    fn segments_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text("!!");
+
+        assert_eq!(
+            layout.segments().collect::<Vec<_>>(),
+            vec![
+                (Edge { x: 5, y: -10 }, Edge { x: 5, y: 10 }),
+                (Edge { x: 15, y: -10 }, Edge { x: 15, y: 10 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn layout_text_with_options_wraps_words() {
+        let font = HersheyFont::new("  720  1NV\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text_with_options(
+            "!! !!! !\n!",
+            &LayoutOptions {
+                max_width: Some(40),
+                line_spacing: 1.5,
+                alignment: Alignment::Right,
+                ..LayoutOptions::default()
+            },
+        );
+        let lines = layout
+            .lines
+            .iter()
+            .map(|line| (line.glyphs.len(), line.x, line.y, line.width))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                (2, 20, 0, 20),
+                (3, 10, 30, 30),
+                (1, 30, 60, 10),
+                (1, 30, 90, 10)
+            ]
+        );
+        assert_eq!(layout.line_glyphs(2)[0].x, 30);
+        assert_eq!(layout.width, 40);
+        assert_eq!(layout.height, 110);
+        assert_eq!(
+            layout
+                .glyphs
+                .iter()
+                .map(|glyph| glyph.index)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 3, 4, 5, 7, 9]
+        );
+    }
+
+    #[test]
+    fn layout_text_with_options_applies_kerning_and_tracking() {
+        let font = HersheyFont::new("  720  1NV\n  720  3MWRHR\\\n  720  3MWRHR\\").unwrap();
+        let options = LayoutOptions {
+            tracking: 2,
+            kerning: Some("!\" -4".parse().unwrap()),
+            ..LayoutOptions::default()
+        };
+        let layout = font.layout_text_with_options("!\"!", &options);
+
+        assert_eq!(
+            layout.positions(),
+            vec![('!', 0, 0), ('"', 8, 0), ('!', 20, 0)]
+        );
+        assert_eq!(layout.width, 30);
+        assert_eq!(font.measure_text_with_options("!\"!", &options), 30);
+    }
+
+    #[test]
+    fn layout_text_falls_back_for_missing_space() {
+        let font = HersheyFont::new("").unwrap();
+        let layout = font.layout_text("  ");
+
+        assert_eq!(layout.width, 2);
+        assert_eq!(layout.glyphs.len(), 2);
+    }
+
+    #[test]
+    fn layout_text_with_options_lays_out_right_to_left() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\\n  720  3NVRHR\\").unwrap();
+        let layout = font.layout_text_with_options(
+            "!\"\n!",
+            &LayoutOptions {
+                alignment: Alignment::Right,
+                direction: Direction::RightToLeft,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(
+            layout.positions(),
+            vec![('!', 8, 0), ('"', 0, 0), ('!', 8, 20)]
+        );
+        assert_eq!(layout.glyphs[0].paths[0][0], Edge { x: 13, y: -10 });
+        assert_eq!(layout.line_bounds(0).unwrap().width(), 9.0);
+        assert_eq!(layout.line_bounds(2), None);
+    }
+
+    #[test]
+    fn layout_text_with_options_lays_out_top_to_bottom() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text_with_options(
+            "!!\n!",
+            &LayoutOptions {
+                direction: Direction::TopToBottom,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(
+            layout.positions(),
+            vec![('!', 5, 0), ('!', 5, 20), ('!', 25, 0)]
+        );
+        assert_eq!((layout.width, layout.height), (40, 40));
+        assert_eq!(layout.lines[1].x, 20);
+    }
+
+    #[test]
+    fn layout_text_with_options_wraps_and_aligns_columns() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text_with_options(
+            "!! !",
+            &LayoutOptions {
+                max_width: Some(50),
+                tracking: 2,
+                alignment: Alignment::Center,
+                direction: Direction::TopToBottom,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(
+            layout.positions(),
+            vec![('!', 5, 4), ('!', 5, 26), ('!', 25, 15)]
+        );
+        assert_eq!((layout.width, layout.height), (40, 50));
+        assert_eq!(layout.glyphs[0].paths[0][0], Edge { x: 10, y: -6 });
+    }
+
+    #[test]
+    fn layout_text_with_options_applies_anchor() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text_with_options(
+            "!!\n!",
+            &LayoutOptions {
+                anchor: Some(Anchor::Center),
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(
+            layout.positions(),
+            vec![('!', -10, -10), ('!', 0, -10), ('!', -10, 10)]
+        );
+        assert_eq!(layout.lines[1].y, 10);
+
+        let bounds = layout.bounds().unwrap();
+
+        assert_eq!((bounds.left, bounds.top), (-5.0, -20.0));
+        assert_eq!((bounds.right, bounds.bottom), (5.0, 20.0));
+    }
+}
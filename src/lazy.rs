@@ -0,0 +1,108 @@
+use crate::font_ref::{HersheyFontRef, HersheyFontRefGetGlyphError};
+use crate::mapping::CharMapping;
+use crate::meta::FontMeta;
+use crate::{HersheyFont, HersheyFontNewError, HersheyGlyph, ParseOptions};
+use std::cell::OnceCell;
+
+#[derive(Clone, Debug)]
+pub struct LazyHersheyFont<'a> {
+    source: HersheyFontRef<'a>,
+    glyphs: Vec<OnceCell<HersheyGlyph>>,
+}
+
+impl<'a> LazyHersheyFont<'a> {
+    pub fn new(data: &'a str) -> LazyHersheyFont<'a> {
+        LazyHersheyFont::new_with_options(data, &ParseOptions::default())
+    }
+
+    pub fn new_with_options(data: &'a str, options: &ParseOptions) -> LazyHersheyFont<'a> {
+        let source = HersheyFontRef::new_with_options(data, options);
+
+        LazyHersheyFont {
+            glyphs: (0..source.len()).map(|_| OnceCell::new()).collect(),
+            source,
+        }
+    }
+
+    pub fn with_mapping(mut self, mapping: CharMapping) -> LazyHersheyFont<'a> {
+        self.source = self.source.with_mapping(mapping);
+        self
+    }
+
+    pub fn meta(&self) -> Option<FontMeta> {
+        self.source.meta()
+    }
+
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    pub fn decoded_len(&self) -> usize {
+        self.glyphs
+            .iter()
+            .filter(|cell| cell.get().is_some())
+            .count()
+    }
+
+    pub fn get_glyph(&self, glyph: char) -> Result<&HersheyGlyph, HersheyFontRefGetGlyphError> {
+        let cell = self
+            .source
+            .index(glyph)
+            .and_then(|index| self.glyphs.get(index))
+            .ok_or(HersheyFontRefGetGlyphError::GlyphNotFound(format!(
+                "Glyph {} not found in font",
+                glyph
+            )))?;
+
+        if let Some(decoded) = cell.get() {
+            return Ok(decoded);
+        }
+
+        let decoded = self.source.get_glyph(glyph)?;
+
+        Ok(cell.get_or_init(|| decoded))
+    }
+
+    pub fn to_font(&self) -> Result<HersheyFont, HersheyFontNewError> {
+        self.source.to_font()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn get_glyph_decodes_on_first_access() {
+        let font = LazyHersheyFont::new("  720  1JZ\n  720  3G][BIb\n  720  3G][Bb");
+
+        assert_eq!(font.decoded_len(), 0);
+        assert_eq!(font.get_glyph('!').unwrap().paths.len(), 1);
+        assert!(std::ptr::eq(
+            font.get_glyph('!').unwrap(),
+            font.get_glyph('!').unwrap()
+        ));
+        assert_eq!(font.decoded_len(), 1);
+        assert!(matches!(
+            font.get_glyph('"'),
+            Err(HersheyFontRefGetGlyphError::ParseError(_))
+        ));
+        assert!(font.get_glyph('\n').is_err());
+        assert_eq!(font.decoded_len(), 1);
+    }
+
+    #[test]
+    fn get_glyph_uses_mapping() {
+        let font = LazyHersheyFont::new("  699  1JZ\n  720  3G][BIb")
+            .with_mapping(CharMapping::Custom(HashMap::from([('é', 1)])));
+
+        assert_eq!(font.get_glyph('é').unwrap().number, 720);
+        assert!(font.get_glyph(' ').is_err());
+        assert_eq!(font.decoded_len(), 1);
+    }
+}
@@ -1,5 +1,79 @@
-use anyhow::{anyhow, Result};
+use error::{ParseError, ParseErrorKind};
+use std::borrow::Cow;
 use std::cmp;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+pub mod area;
+pub mod atlas;
+pub mod bounds;
+pub mod builder;
+pub mod canonical;
+pub mod classify;
+pub mod collection;
+pub mod coordinates;
+pub mod coverage;
+pub mod csv;
+pub mod derive;
+#[cfg(feature = "dxf")]
+pub mod dxf;
+pub mod edit;
+pub mod embed;
+pub mod eps;
+pub mod error;
+pub mod fill;
+pub mod fingerprint;
+pub mod fixed;
+pub mod flat;
+pub mod font_ref;
+pub mod font_set;
+pub mod gcode;
+pub mod geometry;
+pub mod glyph_table;
+pub mod hit;
+pub mod hmp;
+pub mod hpgl;
+pub mod html;
+pub mod index;
+pub mod interpolate;
+pub mod iter;
+pub mod jhf;
+pub mod json;
+pub mod kerning;
+pub mod layout;
+pub mod lazy;
+pub mod mapping;
+pub mod memory;
+pub mod mesh;
+pub mod meta;
+pub mod metrics;
+pub mod optimize;
+pub mod order;
+pub mod parallel;
+pub mod pdf;
+pub mod plot_time;
+pub mod raster;
+pub mod reader;
+pub mod report;
+pub mod scaled;
+pub mod sdf;
+pub mod smooth;
+pub mod specimen;
+pub mod stats;
+pub mod stroke;
+pub mod style;
+pub mod subset;
+pub mod substitute;
+pub mod svg;
+pub mod svg_import;
+pub mod symbols;
+pub mod text;
+pub mod transform;
+pub mod units;
+pub mod validate;
+pub mod watermark;
+
+pub use text::{text_to_paths, text_to_paths_anchored, text_to_paths_with_options, Anchor};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Edge {
@@ -7,28 +81,71 @@ pub struct Edge {
     pub y: i32,
 }
 
+impl Edge {
+    pub fn distance_to(&self, other: &Edge) -> f64 {
+        ((other.x - self.x) as f64).hypot((other.y - self.y) as f64)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointF {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl From<Edge> for PointF {
+    fn from(edge: Edge) -> PointF {
+        PointF {
+            x: edge.x as f64,
+            y: edge.y as f64,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct HersheyFont {
     pub top: i32,
     pub right: i32,
     pub bottom: i32,
     pub left: i32,
+    pub metadata: BTreeMap<String, String>,
+    pub coordinate_system: coordinates::CoordinateSystem,
+    pub mapping: mapping::CharMapping,
     glyphs: Vec<HersheyGlyph>,
+    numbers: index::NumberIndex,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct HersheyGlyph {
+    pub number: u32,
     pub top: i32,
     pub right: i32,
     pub bottom: i32,
     pub left: i32,
     pub paths: Vec<Vec<Edge>>,
+    source: Option<GlyphSource>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphSource {
+    pub line: usize,
+    pub span: Range<usize>,
+    pub text: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParseOptions {
+    pub trust_data_length: bool,
+    pub keep_source: bool,
+    pub lenient: bool,
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum HersheyFontNewError {
-    #[error("{1}")]
-    ParseError(#[source] Box<dyn std::error::Error>, String),
+    #[error("{0}")]
+    ParseError(#[from] ParseError),
+    #[error("{0}")]
+    GlyphNotFound(String),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -39,23 +156,49 @@ pub enum HersheyFontGetGlyphError {
 
 impl HersheyFont {
     pub fn new(data: &str) -> Result<HersheyFont, HersheyFontNewError> {
-        let glyphs = data
-            .split('\n')
-            .enumerate()
-            .filter(|(_, line)| !line.is_empty())
-            .map(|(i, line)| match line_to_hershey_glyph(line) {
-                Ok(glyph) => Ok(glyph),
-                Err(e) => Err(HersheyFontNewError::ParseError(
-                    e.into(),
-                    format!("Error parsing line {}", i + 1),
-                )),
-            })
-            .collect::<Result<Vec<_>, HersheyFontNewError>>()?;
+        HersheyFont::new_with_options(data, &ParseOptions::default())
+    }
+
+    pub fn new_with_options(
+        data: &str,
+        options: &ParseOptions,
+    ) -> Result<HersheyFont, HersheyFontNewError> {
+        let (metadata, lines) = split_records(data);
+
+        let glyphs = lines
+            .into_iter()
+            .map(|record| record_to_hershey_glyph(&record, options))
+            .collect::<Result<Vec<_>, ParseError>>()?;
 
-        let points_iter = glyphs
+        Ok(HersheyFont::from_parts(metadata, glyphs))
+    }
+
+    pub fn new_lenient(data: &str) -> (HersheyFont, Vec<ParseError>) {
+        let (metadata, lines) = split_records(data);
+        let options = ParseOptions {
+            lenient: true,
+            ..ParseOptions::default()
+        };
+        let mut warnings = Vec::new();
+
+        let glyphs = lines
             .iter()
-            .flat_map(|glyph| glyph.paths.clone())
-            .flatten();
+            .map(|record| {
+                let (glyph, warning) = record_to_hershey_glyph_lenient(record, &options);
+
+                warnings.extend(warning);
+                glyph
+            })
+            .collect();
+
+        (HersheyFont::from_parts(metadata, glyphs), warnings)
+    }
+
+    pub(crate) fn from_parts(
+        metadata: BTreeMap<String, String>,
+        glyphs: Vec<HersheyGlyph>,
+    ) -> HersheyFont {
+        let points_iter = glyphs.iter().flat_map(HersheyGlyph::points);
 
         let (top, right, bottom, left) = points_iter.fold(
             (i32::MAX, i32::MIN, i32::MIN, i32::MAX),
@@ -69,40 +212,323 @@ impl HersheyFont {
             },
         );
 
-        Ok(HersheyFont {
+        HersheyFont {
             top,
             right,
             bottom,
             left,
+            coordinate_system: coordinates::CoordinateSystem::default(),
+            mapping: mapping::CharMapping::default(),
+            metadata,
+            numbers: index::NumberIndex::new(glyphs.iter().map(|glyph| glyph.number)),
             glyphs,
-        })
+        }
+    }
+
+    pub(crate) fn get_glyph_checked(&self, glyph: char) -> Option<&HersheyGlyph> {
+        self.glyphs.get(self.mapping.index(&self.numbers, glyph)?)
     }
 
     pub fn get_glyph(&self, glyph: char) -> Result<&HersheyGlyph, HersheyFontGetGlyphError> {
-        self.glyphs
-            .get((glyph as usize) - 32)
+        self.get_glyph_checked(glyph)
             .ok_or(HersheyFontGetGlyphError::GlyphNotFound(format!(
                 "Glyph {} not found in font",
                 glyph
             )))
     }
+
+    pub fn get_glyph_by_number(
+        &self,
+        number: u32,
+    ) -> Result<&HersheyGlyph, HersheyFontGetGlyphError> {
+        self.numbers
+            .get(number)
+            .and_then(|index| self.glyphs.get(index))
+            .ok_or(HersheyFontGetGlyphError::GlyphNotFound(format!(
+                "Glyph number {} not found in font",
+                number
+            )))
+    }
+
+    pub fn chars_for_glyph(&self, index: usize) -> Vec<char> {
+        if index >= self.glyphs.len() {
+            return Vec::new();
+        }
+
+        self.mapping.chars(&self.numbers, index)
+    }
 }
 
-fn line_to_hershey_glyph(line: &str) -> Result<HersheyGlyph> {
-    if line.len() < 10 {
-        return Err(anyhow!("Invalid glyph data"));
+impl HersheyGlyph {
+    pub fn new(left: i32, right: i32, paths: Vec<Vec<Edge>>) -> HersheyGlyph {
+        let mut glyph = HersheyGlyph {
+            number: 0,
+            top: i32::MAX,
+            right,
+            bottom: i32::MIN,
+            left,
+            paths,
+            source: None,
+        };
+
+        glyph.update_bounds();
+        glyph
+    }
+
+    pub fn source(&self) -> Option<&GlyphSource> {
+        self.source.as_ref()
+    }
+
+    pub(crate) fn with_source(mut self, record: &RecordLine) -> HersheyGlyph {
+        self.source = Some(GlyphSource {
+            line: record.line + 1,
+            span: record.span.clone(),
+            text: record.text.to_string(),
+        });
+        self
+    }
+
+    pub(crate) fn update_bounds(&mut self) {
+        let (top, bottom) = self
+            .points()
+            .fold((i32::MAX, i32::MIN), |(top, bottom), edge| {
+                (cmp::min(top, edge.y), cmp::max(bottom, edge.y))
+            });
+
+        self.top = top;
+        self.bottom = bottom;
     }
 
-    let contents = &line[5..];
+    pub fn paths(&self) -> impl Iterator<Item = &[Edge]> + '_ {
+        self.paths.iter().map(Vec::as_slice)
+    }
 
-    let num_pairs = (&contents[..3].trim().parse::<i32>()? - 1) as usize;
+    pub fn points(&self) -> impl Iterator<Item = &Edge> + '_ {
+        self.paths.iter().flatten()
+    }
 
-    let left = char_to_int(&contents.chars().nth(3).unwrap());
-    let right = char_to_int(&contents.chars().nth(4).unwrap());
+    pub fn segments(&self) -> impl Iterator<Item = (Edge, Edge)> + '_ {
+        self.paths()
+            .flat_map(|path| path.windows(2).map(|pair| (pair[0], pair[1])))
+    }
+}
+
+impl std::str::FromStr for HersheyFont {
+    type Err = HersheyFontNewError;
 
-    if contents.len() != 5 + num_pairs * 2 {
-        return Err(anyhow!("Invalid glyph data"));
+    fn from_str(data: &str) -> Result<HersheyFont, HersheyFontNewError> {
+        HersheyFont::new(data)
     }
+}
+
+impl TryFrom<&[u8]> for HersheyFont {
+    type Error = HersheyFontNewError;
+
+    fn try_from(data: &[u8]) -> Result<HersheyFont, HersheyFontNewError> {
+        let data = std::str::from_utf8(data).map_err(|e| {
+            let valid = &data[..e.valid_up_to()];
+            let line_start = valid
+                .iter()
+                .rposition(|byte| *byte == b'\n')
+                .map_or(0, |i| i + 1);
+
+            ParseError::new(
+                ParseErrorKind::InvalidUtf8,
+                Some(valid.len() - line_start + 1),
+            )
+            .at_line(valid.iter().filter(|byte| **byte == b'\n').count() + 1)
+        })?;
+
+        HersheyFont::new(data)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RecordLine<'a> {
+    pub(crate) line: usize,
+    pub(crate) span: Range<usize>,
+    pub(crate) text: Cow<'a, str>,
+}
+
+const WRAP_COLUMN: usize = 72;
+
+fn record_length(line: &str) -> Option<usize> {
+    let count = line.get(5..8)?.trim().parse::<usize>().ok()?;
+
+    Some(8 + 2 * count)
+}
+
+pub(crate) fn is_wrapped(record: &RecordLine) -> bool {
+    let text = record.text.trim_end_matches('\r');
+
+    text.len().is_multiple_of(WRAP_COLUMN)
+        && record_length(text).is_some_and(|length| text.len() < length)
+}
+
+pub(crate) fn split_records(data: &str) -> (BTreeMap<String, String>, Vec<RecordLine<'_>>) {
+    let mut metadata = BTreeMap::new();
+    let mut lines = Vec::new();
+    let mut offset = 0;
+
+    for (i, line) in data.split('\n').enumerate() {
+        let line_offset = offset;
+        offset += line.len() + 1;
+
+        if let Some(record) = lines.last_mut().filter(|record| is_wrapped(record)) {
+            let text = record.text.to_mut();
+
+            text.truncate(text.trim_end_matches('\r').len());
+            text.push_str(line);
+            record.span.end = line_offset + line.len();
+            continue;
+        }
+
+        if line.is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        if lines.is_empty() {
+            if let Some((key, value)) = line_to_metadata(line) {
+                metadata.insert(key, value);
+                continue;
+            }
+        }
+
+        lines.push(RecordLine {
+            line: i,
+            span: line_offset..line_offset + line.len(),
+            text: Cow::Borrowed(line),
+        });
+    }
+
+    (metadata, lines)
+}
+
+pub(crate) fn metadata_entry(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+    let key = key.trim();
+
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        || key.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    Some((key, value.trim()))
+}
+
+pub(crate) fn line_to_metadata(line: &str) -> Option<(String, String)> {
+    metadata_entry(line).map(|(key, value)| (key.to_ascii_lowercase(), value.to_string()))
+}
+
+pub(crate) fn record_to_hershey_glyph_lenient(
+    record: &RecordLine,
+    options: &ParseOptions,
+) -> (HersheyGlyph, Option<ParseError>) {
+    let strict = ParseOptions {
+        lenient: false,
+        ..*options
+    };
+    let error = match record_to_hershey_glyph(record, &strict) {
+        Ok(glyph) => return (glyph, None),
+        Err(e) => e,
+    };
+    let repaired = RecordLine {
+        line: record.line,
+        span: record.span.clone(),
+        text: Cow::Borrowed(record.text.trim_end()),
+    };
+    let trusted = ParseOptions {
+        trust_data_length: true,
+        ..strict
+    };
+
+    let glyph = record_to_hershey_glyph(&repaired, &trusted).unwrap_or_else(|_| {
+        let mut glyph = HersheyGlyph::new(0, 0, Vec::new());
+
+        glyph.number = record
+            .text
+            .get(..5)
+            .and_then(|number| number.trim().parse().ok())
+            .unwrap_or(0);
+        glyph
+    });
+
+    (glyph, Some(error))
+}
+
+pub(crate) fn record_to_hershey_glyph(
+    record: &RecordLine,
+    options: &ParseOptions,
+) -> Result<HersheyGlyph, ParseError> {
+    if options.lenient {
+        return Ok(record_to_hershey_glyph_lenient(record, options).0);
+    }
+
+    let glyph =
+        line_to_hershey_glyph(&record.text, options).map_err(|e| e.at_line(record.line + 1))?;
+
+    if options.keep_source {
+        Ok(glyph.with_source(record))
+    } else {
+        Ok(glyph)
+    }
+}
+
+pub(crate) fn line_to_hershey_glyph(
+    line: &str,
+    options: &ParseOptions,
+) -> Result<HersheyGlyph, ParseError> {
+    let bytes = line.as_bytes();
+
+    if let Some(column) = bytes.iter().position(|byte| !byte.is_ascii()) {
+        return Err(ParseError::new(
+            ParseErrorKind::NonAsciiCharacter,
+            Some(column + 1),
+        ));
+    }
+
+    if bytes.len() < 10 {
+        return Err(ParseError::new(ParseErrorKind::TruncatedRecord, None));
+    }
+
+    let count = line[5..8].trim();
+    let declared_pairs = count
+        .parse::<usize>()
+        .ok()
+        .and_then(|count| count.checked_sub(1))
+        .ok_or(ParseError::new(
+            ParseErrorKind::InvalidVertexCount(count.to_string()),
+            Some(6),
+        ))?;
+    let actual_pairs = (bytes.len() - 10) / 2;
+
+    let num_pairs = if options.trust_data_length {
+        actual_pairs
+    } else if bytes.len() != 10 + declared_pairs * 2 {
+        return Err(ParseError::new(
+            ParseErrorKind::VertexCountMismatch {
+                declared: declared_pairs + 1,
+                found: actual_pairs + 1,
+            },
+            None,
+        ));
+    } else {
+        declared_pairs
+    };
+
+    if let Some(column) = (8..10 + num_pairs * 2).find(|i| bytes[*i].is_ascii_control()) {
+        return Err(ParseError::new(
+            ParseErrorKind::InvalidCoordinate(bytes[column] as char),
+            Some(column + 1),
+        ));
+    }
+
+    let left = char_to_int(&(bytes[8] as char));
+    let right = char_to_int(&(bytes[9] as char));
 
     let mut top = i32::MAX;
     let mut bottom = i32::MIN;
@@ -110,15 +536,13 @@ fn line_to_hershey_glyph(line: &str) -> Result<HersheyGlyph> {
     let mut paths = Vec::new();
     let mut path = Vec::new();
 
-    for i in 0..num_pairs {
-        let pair = &contents[5 + (i * 2)..7 + (i * 2)];
-
-        if pair == " R" && !path.is_empty() {
+    for pair in bytes[10..].chunks_exact(2).take(num_pairs) {
+        if pair == b" R" && !path.is_empty() {
             paths.push(path);
             path = Vec::new()
         } else {
-            let x = char_to_int(&pair.chars().next().unwrap());
-            let y = char_to_int(&pair.chars().nth(1).unwrap());
+            let x = char_to_int(&(pair[0] as char));
+            let y = char_to_int(&(pair[1] as char));
 
             top = cmp::min(top, y);
             bottom = cmp::max(bottom, y);
@@ -132,11 +556,13 @@ fn line_to_hershey_glyph(line: &str) -> Result<HersheyGlyph> {
     }
 
     Ok(HersheyGlyph {
+        number: line[..5].trim().parse().unwrap_or(0),
         top,
         right,
         bottom,
         left,
         paths,
+        source: None,
     })
 }
 
@@ -152,10 +578,141 @@ mod tests {
     fn new_returns_error_if_data_is_invalid() {
         let result = HersheyFont::new(" ");
 
-        assert!(matches!(result, Err(HersheyFontNewError::ParseError(_, _))));
+        assert!(matches!(result, Err(HersheyFontNewError::ParseError(_))));
+    }
+
+    #[test]
+    fn new_skips_comments_and_reads_metadata() {
+        let font = HersheyFont::new(
+            "# Futura light\nname: futural\nSource: NTIS\n# glyphs follow\n  720  3G][BIb",
+        )
+        .unwrap();
+
+        assert_eq!(
+            font.metadata.get("name").map(String::as_str),
+            Some("futural")
+        );
+        assert_eq!(
+            font.metadata.get("source").map(String::as_str),
+            Some("NTIS")
+        );
+        assert!(font.get_glyph(' ').is_ok());
+    }
+
+    #[test]
+    fn new_reports_declared_and_actual_vertex_counts() {
+        let result = HersheyFont::new("  720  4G][BIb");
+
+        let Err(HersheyFontNewError::ParseError(error)) = result else {
+            panic!("expected parse error");
+        };
+
+        assert_eq!(
+            error,
+            ParseError {
+                line: 1,
+                column: None,
+                kind: ParseErrorKind::VertexCountMismatch {
+                    declared: 4,
+                    found: 3
+                },
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Error parsing line 1: declared 4 vertices but found 3"
+        );
+    }
+
+    #[test]
+    fn new_with_options_trusts_data_length() {
+        let options = ParseOptions {
+            trust_data_length: true,
+            ..ParseOptions::default()
+        };
+        let font = HersheyFont::new_with_options("  720  9G][BIb", &options).unwrap();
+
+        assert_eq!(font.get_glyph(' ').unwrap().paths[0].len(), 2);
+    }
+
+    #[test]
+    fn from_str_works() {
+        let font = "  720  3G][BIb".parse::<HersheyFont>();
+
+        assert!(font.is_ok());
+    }
+
+    #[test]
+    fn try_from_bytes_works() {
+        let font = HersheyFont::try_from("  720  3G][BIb".as_bytes());
+
+        assert!(font.is_ok());
+    }
+
+    #[test]
+    fn try_from_bytes_returns_error_if_data_is_not_utf8() {
+        let result = HersheyFont::try_from(&[0xff, 0xfe][..]);
+
+        assert!(matches!(result, Err(HersheyFontNewError::ParseError(_))));
     }
 
     #[test]
+    fn parse_errors_carry_position() {
+        let error = |result: Result<HersheyFont, HersheyFontNewError>| match result {
+            Err(HersheyFontNewError::ParseError(error)) => (error.line, error.column, error.kind),
+            _ => panic!("expected parse error"),
+        };
+
+        assert_eq!(
+            error(HersheyFont::try_from(&b"  720  1JZ\n  720  1J\xff"[..])),
+            (2, Some(10), ParseErrorKind::InvalidUtf8)
+        );
+        assert_eq!(
+            error(HersheyFont::new("  720  1JZ\n\n  720  2JZR\t")),
+            (3, Some(12), ParseErrorKind::InvalidCoordinate('\t'))
+        );
+        assert_eq!(
+            error(HersheyFont::new("  720 x1JZ")),
+            (
+                1,
+                Some(6),
+                ParseErrorKind::InvalidVertexCount("x1".to_string())
+            )
+        );
+        assert_eq!(
+            error(HersheyFont::new("  720")),
+            (1, None, ParseErrorKind::TruncatedRecord)
+        );
+    }
+
+    #[test]
+    fn new_with_options_keeps_glyph_source() {
+        let options = ParseOptions {
+            keep_source: true,
+            ..ParseOptions::default()
+        };
+        let font = HersheyFont::new_with_options("# comment\n  720  3G][BIb", &options).unwrap();
+
+        assert_eq!(
+            font.get_glyph(' ').unwrap().source(),
+            Some(&GlyphSource {
+                line: 2,
+                span: 10..24,
+                text: "  720  3G][BIb".to_string(),
+            })
+        );
+        assert_eq!(
+            HersheyFont::new("  720  3G][BIb")
+                .unwrap()
+                .get_glyph(' ')
+                .unwrap()
+                .source(),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(clippy::redundant_pattern_matching)]
     fn get_glyph_works() {
         let font = HersheyFont::new("  720  3G][BIb").unwrap();
         let glyph = font.get_glyph(' ');
@@ -174,6 +731,51 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn line_to_metadata_works() {
+        assert_eq!(
+            line_to_metadata("spacing: 16"),
+            Some(("spacing".to_string(), "16".to_string()))
+        );
+        assert_eq!(line_to_metadata("  720  3G][BIb"), None);
+    }
+
+    #[test]
+    fn chars_for_glyph_works() {
+        let font = HersheyFont::new("  720  3G][BIb\n  720  3G][BIb").unwrap();
+
+        assert_eq!(font.chars_for_glyph(1), vec!['!']);
+        assert_eq!(font.chars_for_glyph(2), vec![]);
+    }
+
+    #[test]
+    fn glyph_new_works() {
+        let glyph = HersheyGlyph::new(
+            -11,
+            11,
+            vec![vec![Edge { x: 9, y: -16 }, Edge { x: -9, y: 16 }]],
+        );
+
+        assert_eq!(
+            glyph,
+            line_to_hershey_glyph("    0  3G][BIb", &ParseOptions::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn segments_works() {
+        let font = HersheyFont::new("  720  6G]RBRK RRRRW").unwrap();
+        let segments = font.get_glyph(' ').unwrap().segments().collect::<Vec<_>>();
+
+        assert_eq!(
+            segments,
+            vec![
+                (Edge { x: 0, y: -16 }, Edge { x: 0, y: -7 }),
+                (Edge { x: 0, y: 0 }, Edge { x: 0, y: 5 }),
+            ]
+        );
+    }
+
     #[test]
     fn char_to_int_works() {
         assert_eq!(char_to_int(&'R'), 0);
@@ -182,26 +784,150 @@ mod tests {
         assert_eq!(char_to_int(&'Q'), -1);
     }
 
+    #[test]
+    fn new_reassembles_wrapped_records() {
+        let pairs = "RHR\\".repeat(19) + "R\\";
+        let record = format!("  720 40JZ{}", pairs);
+        let wrapped = format!("{}\n{}\n  720  1JZ", &record[..72], &record[72..]);
+        let font = HersheyFont::new_with_options(
+            &wrapped,
+            &ParseOptions {
+                keep_source: true,
+                ..ParseOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(font.glyphs.len(), 2);
+        assert_eq!(
+            font.glyphs[0].paths,
+            HersheyFont::new(&record).unwrap().glyphs[0].paths
+        );
+        assert_eq!(font.glyphs[0].source().unwrap().span, 0..89);
+        assert_eq!(font.glyphs[1].source().unwrap().line, 3);
+    }
+
     #[test]
     fn line_to_hershey_glyph_works() {
-        let glyph = line_to_hershey_glyph("  720  3G][BIb").unwrap();
+        let glyph = line_to_hershey_glyph("  720  3G][BIb", &ParseOptions::default()).unwrap();
 
         assert_eq!(
             glyph,
             HersheyGlyph {
+                number: 720,
                 top: -16,
                 right: 11,
                 bottom: 16,
                 left: -11,
-                paths: vec![vec![Edge { x: 9, y: -16 }, Edge { x: -9, y: 16 }]]
+                paths: vec![vec![Edge { x: 9, y: -16 }, Edge { x: -9, y: 16 }]],
+                source: None,
             }
         );
     }
 
+    #[test]
+    fn get_glyph_returns_error_below_space() {
+        let font = HersheyFont::new("  720  1JZ").unwrap();
+
+        assert!(font.get_glyph('\n').is_err());
+        assert!(font.get_glyph('\0').is_err());
+    }
+
+    #[test]
+    fn get_glyph_by_number_works() {
+        let font = HersheyFont::new("  699  1JZ\n 2273  3JZRHR\\").unwrap();
+
+        assert_eq!(
+            font.get_glyph_by_number(2273).unwrap(),
+            font.get_glyph('!').unwrap()
+        );
+        assert_eq!(font.get_glyph_by_number(699).unwrap().number, 699);
+        assert!(font.get_glyph_by_number(720).is_err());
+    }
+
+    #[test]
+    fn paths_and_points_borrow_glyph_data() {
+        let font = HersheyFont::new("  701  5JZRHR\\ RRH").unwrap();
+        let glyph = font.get_glyph(' ').unwrap();
+
+        assert_eq!(
+            glyph.paths().map(<[Edge]>::len).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert_eq!(glyph.points().count(), 3);
+        assert_eq!(glyph.points().next(), Some(&Edge { x: 0, y: -10 }));
+    }
+
+    #[test]
+    fn new_lenient_repairs_and_skips_bad_records() {
+        let (font, warnings) =
+            HersheyFont::new_lenient("  699  1JZ  \n  714  9MWRHR\\\n  715 é1JZ\n  716  1JZ");
+
+        assert_eq!(font.len(), 4);
+        assert_eq!(font.get_glyph('!').unwrap().paths.len(), 1);
+        assert_eq!(font.get_glyph('"').unwrap().number, 715);
+        assert!(font.get_glyph('"').unwrap().paths.is_empty());
+        assert_eq!(font.get_glyph('#').unwrap().number, 716);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|warning| warning.line)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            warnings[1].kind,
+            ParseErrorKind::VertexCountMismatch {
+                declared: 9,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn new_with_options_lenient_does_not_fail() {
+        let lenient = ParseOptions {
+            lenient: true,
+            ..ParseOptions::default()
+        };
+
+        assert!(HersheyFont::new(" ").is_err());
+        assert_eq!(
+            HersheyFont::new_with_options(" \n  720  3G][BIb", &lenient)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
     #[test]
     fn line_to_hershey_glyph_returns_error_if_glyph_data_is_invalid() {
-        let result = line_to_hershey_glyph("");
+        let result = line_to_hershey_glyph("", &ParseOptions::default());
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn new_returns_errors_instead_of_panicking_on_malformed_input() {
+        let inputs = [
+            "  720  3G]é",
+            "  720  3G][BIébb",
+            "ééééé  3G][BIb",
+            "  720 éé][BIb",
+            "  720  0G]",
+            "  720 -1G]",
+            "  720999G][BIb",
+            "  7\u{2003}0  3G][BIb",
+        ];
+
+        for input in inputs {
+            let trusted = ParseOptions {
+                trust_data_length: true,
+                ..ParseOptions::default()
+            };
+
+            assert!(HersheyFont::new(input).is_err(), "{}", input);
+            let _ = HersheyFont::new_with_options(input, &trusted);
+        }
+    }
 }
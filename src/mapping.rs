@@ -0,0 +1,101 @@
+use crate::hmp::HersheyMap;
+use crate::index::NumberIndex;
+use crate::HersheyFont;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum CharMapping {
+    #[default]
+    AsciiOffset,
+    Custom(HashMap<char, usize>),
+    HersheyMapFile(HersheyMap),
+}
+
+impl CharMapping {
+    pub(crate) fn index(&self, numbers: &NumberIndex, c: char) -> Option<usize> {
+        match self {
+            CharMapping::AsciiOffset => (c as usize).checked_sub(32),
+            CharMapping::Custom(map) => map.get(&c).copied(),
+            CharMapping::HersheyMapFile(map) => (c as usize)
+                .checked_sub(32)
+                .and_then(|position| map.numbers.get(position))
+                .and_then(|number| numbers.get(*number)),
+        }
+    }
+
+    pub(crate) fn chars(&self, glyph_numbers: &NumberIndex, index: usize) -> Vec<char> {
+        match self {
+            CharMapping::AsciiOffset => u32::try_from(index + 32)
+                .ok()
+                .and_then(char::from_u32)
+                .into_iter()
+                .collect(),
+            CharMapping::Custom(map) => {
+                let mut chars = map
+                    .iter()
+                    .filter(|(_, mapped)| **mapped == index)
+                    .map(|(c, _)| *c)
+                    .collect::<Vec<_>>();
+
+                chars.sort_unstable();
+                chars
+            }
+            CharMapping::HersheyMapFile(map) => map
+                .numbers
+                .iter()
+                .enumerate()
+                .filter(|(_, number)| glyph_numbers.get(**number) == Some(index))
+                .filter_map(|(position, _)| char::from_u32(position as u32 + 32))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn heap_size(&self) -> usize {
+        match self {
+            CharMapping::AsciiOffset => 0,
+            CharMapping::Custom(map) => map.capacity() * std::mem::size_of::<(char, usize)>(),
+            CharMapping::HersheyMapFile(map) => map.numbers.capacity() * std::mem::size_of::<u32>(),
+        }
+    }
+}
+
+impl HersheyFont {
+    pub fn with_mapping(mut self, mapping: CharMapping) -> HersheyFont {
+        self.mapping = mapping;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font() -> HersheyFont {
+        HersheyFont::new("  699  1JZ\n  714  3JZRHR\\\n  710  3MWRHR\\").unwrap()
+    }
+
+    #[test]
+    fn custom_mapping_works() {
+        let font = font().with_mapping(CharMapping::Custom(HashMap::from([
+            ('é', 2),
+            ('e', 2),
+            ('\n', 0),
+        ])));
+
+        assert_eq!(font.get_glyph('é').unwrap().number, 710);
+        assert_eq!(font.get_glyph('\n').unwrap().number, 699);
+        assert!(font.get_glyph('!').is_err());
+        assert_eq!(font.chars_for_glyph(2), vec!['e', 'é']);
+    }
+
+    #[test]
+    fn hershey_map_file_mapping_works() {
+        let map = "699 710 714".parse::<HersheyMap>().unwrap();
+        let font = font().with_mapping(CharMapping::HersheyMapFile(map));
+
+        assert_eq!(font.get_glyph('!').unwrap().number, 710);
+        assert_eq!(font.get_glyph('"').unwrap().number, 714);
+        assert!(font.get_glyph('#').is_err());
+        assert_eq!(font.chars_for_glyph(1), vec!['"']);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::layout::{LayoutLine, PositionedGlyph, TextLayout};
+use crate::{Edge, HersheyFont, HersheyGlyph};
+use std::mem;
+
+fn paths_heap_size(paths: &Vec<Vec<Edge>>) -> usize {
+    paths.capacity() * mem::size_of::<Vec<Edge>>()
+        + paths
+            .iter()
+            .map(|path| path.capacity() * mem::size_of::<Edge>())
+            .sum::<usize>()
+}
+
+impl HersheyGlyph {
+    pub fn heap_size(&self) -> usize {
+        paths_heap_size(&self.paths)
+            + self
+                .source
+                .as_ref()
+                .map_or(0, |source| source.text.capacity())
+    }
+}
+
+impl HersheyFont {
+    pub fn heap_size(&self) -> usize {
+        let metadata = self
+            .metadata
+            .iter()
+            .map(|(key, value)| 2 * mem::size_of::<String>() + key.capacity() + value.capacity())
+            .sum::<usize>();
+
+        self.glyphs.capacity() * mem::size_of::<HersheyGlyph>()
+            + self
+                .glyphs
+                .iter()
+                .map(HersheyGlyph::heap_size)
+                .sum::<usize>()
+            + metadata
+            + self.numbers.heap_size()
+            + self.mapping.heap_size()
+    }
+}
+
+impl TextLayout {
+    pub fn heap_size(&self) -> usize {
+        self.glyphs.capacity() * mem::size_of::<PositionedGlyph>()
+            + self
+                .glyphs
+                .iter()
+                .map(|glyph| paths_heap_size(&glyph.paths))
+                .sum::<usize>()
+            + self.lines.capacity() * mem::size_of::<LayoutLine>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_heap_size_works() {
+        let glyph = HersheyGlyph::new(0, 0, vec![vec![Edge { x: 0, y: 0 }, Edge { x: 1, y: 1 }]]);
+
+        assert_eq!(
+            glyph.heap_size(),
+            mem::size_of::<Vec<Edge>>() + 2 * mem::size_of::<Edge>()
+        );
+    }
+
+    #[test]
+    fn font_heap_size_includes_glyphs() {
+        let font = HersheyFont::new("  720  3G][BIb").unwrap();
+        let glyphs = font
+            .glyphs
+            .iter()
+            .map(HersheyGlyph::heap_size)
+            .sum::<usize>();
+
+        assert!(font.heap_size() >= glyphs + mem::size_of::<HersheyGlyph>());
+    }
+
+    #[test]
+    fn layout_heap_size_includes_glyph_paths() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text("!");
+
+        assert_eq!(
+            layout.heap_size(),
+            layout.glyphs.capacity() * mem::size_of::<PositionedGlyph>()
+                + layout.glyphs[0].paths.capacity() * mem::size_of::<Vec<Edge>>()
+                + layout.glyphs[0].paths[0].capacity() * mem::size_of::<Edge>()
+                + layout.lines.capacity() * mem::size_of::<LayoutLine>()
+        );
+    }
+}
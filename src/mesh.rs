@@ -0,0 +1,135 @@
+use crate::layout::TextLayout;
+use crate::{Edge, HersheyGlyph, PointF};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mesh {
+    pub vertices: Vec<[f32; 2]>,
+    pub indices: Vec<u32>,
+}
+
+impl Mesh {
+    fn push_quad(&mut self, corners: [PointF; 4]) {
+        let base = self.vertices.len() as u32;
+
+        self.vertices.extend(
+            corners
+                .iter()
+                .map(|corner| [corner.x as f32, corner.y as f32]),
+        );
+        self.indices
+            .extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    fn push_segment(&mut self, from: PointF, to: PointF, half_width: f64) {
+        let (dx, dy) = (to.x - from.x, to.y - from.y);
+        let length = dx.hypot(dy);
+        let (ux, uy) = if length == 0.0 {
+            (1.0, 0.0)
+        } else {
+            (dx / length, dy / length)
+        };
+        let (ax, ay) = (ux * half_width, uy * half_width);
+        let (nx, ny) = (-ay, ax);
+
+        self.push_quad([
+            PointF {
+                x: from.x - ax + nx,
+                y: from.y - ay + ny,
+            },
+            PointF {
+                x: to.x + ax + nx,
+                y: to.y + ay + ny,
+            },
+            PointF {
+                x: to.x + ax - nx,
+                y: to.y + ay - ny,
+            },
+            PointF {
+                x: from.x - ax - nx,
+                y: from.y - ay - ny,
+            },
+        ]);
+    }
+}
+
+pub fn tessellate_points(paths: &[Vec<PointF>], stroke_width: f64) -> Mesh {
+    let half_width = stroke_width / 2.0;
+    let mut mesh = Mesh::default();
+
+    for path in paths {
+        match path.as_slice() {
+            [] => {}
+            [point] => mesh.push_segment(*point, *point, half_width),
+            _ => path
+                .windows(2)
+                .for_each(|pair| mesh.push_segment(pair[0], pair[1], half_width)),
+        }
+    }
+
+    mesh
+}
+
+pub fn tessellate(paths: &[Vec<Edge>], scale: f64, stroke_width: f64) -> Mesh {
+    let points = paths
+        .iter()
+        .map(|path| {
+            path.iter()
+                .map(|edge| PointF {
+                    x: edge.x as f64 * scale,
+                    y: edge.y as f64 * scale,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    tessellate_points(&points, stroke_width)
+}
+
+impl HersheyGlyph {
+    pub fn tessellate(&self, scale: f64, stroke_width: f64) -> Mesh {
+        tessellate(&self.paths, scale, stroke_width)
+    }
+}
+
+impl TextLayout {
+    pub fn tessellate(&self, scale: f64, stroke_width: f64) -> Mesh {
+        tessellate(&self.paths(), scale, stroke_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HersheyFont;
+
+    #[test]
+    fn tessellate_works() {
+        let mesh = tessellate(&[vec![Edge { x: 0, y: 0 }, Edge { x: 4, y: 0 }]], 0.5, 2.0);
+
+        assert_eq!(
+            mesh.vertices,
+            vec![[-1.0, 1.0], [3.0, 1.0], [3.0, -1.0], [-1.0, -1.0]]
+        );
+        assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+    }
+
+    #[test]
+    fn tessellate_handles_dots() {
+        let mesh = tessellate_points(&[vec![PointF { x: 1.0, y: 1.0 }], vec![]], 2.0);
+
+        assert_eq!(
+            mesh.vertices,
+            vec![[0.0, 2.0], [2.0, 2.0], [2.0, 0.0], [0.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn text_layout_tessellate_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let mesh = font.layout_text("!!").tessellate(1.0, 1.0);
+
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.indices.len(), 12);
+        assert_eq!(mesh.indices[6], 4);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::HersheyFont;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FontMeta {
+    pub name: Option<String>,
+    pub family: Option<String>,
+    pub source: Option<String>,
+    pub license: Option<String>,
+    pub stroke_width: Option<f64>,
+}
+
+const NAME_KEYS: &[&str] = &["name"];
+const FAMILY_KEYS: &[&str] = &["family"];
+const SOURCE_KEYS: &[&str] = &["source", "file"];
+const LICENSE_KEYS: &[&str] = &["license", "licence", "attribution", "copyright"];
+const STROKE_WIDTH_KEYS: &[&str] = &["stroke-width", "stroke_width"];
+
+pub(crate) fn comment_lines(comment: &str) -> impl Iterator<Item = String> + '_ {
+    comment
+        .split(['\n', '\r'])
+        .map(|line| line.chars().filter(|c| !c.is_control()).collect::<String>())
+        .filter(|line| !line.is_empty())
+}
+
+impl FontMeta {
+    pub(crate) fn from_metadata(metadata: &BTreeMap<String, String>) -> Option<FontMeta> {
+        let get = |keys: &[&str]| keys.iter().find_map(|key| metadata.get(*key).cloned());
+
+        let meta = FontMeta {
+            name: get(NAME_KEYS),
+            family: get(FAMILY_KEYS),
+            source: get(SOURCE_KEYS),
+            license: get(LICENSE_KEYS),
+            stroke_width: get(STROKE_WIDTH_KEYS)
+                .and_then(|value| value.parse::<f64>().ok())
+                .filter(|width| width.is_finite() && *width > 0.0),
+        };
+
+        (meta != FontMeta::default()).then_some(meta)
+    }
+
+    fn write_metadata(&self, metadata: &mut BTreeMap<String, String>) {
+        let fields = [
+            (NAME_KEYS, self.name.clone()),
+            (FAMILY_KEYS, self.family.clone()),
+            (SOURCE_KEYS, self.source.clone()),
+            (LICENSE_KEYS, self.license.clone()),
+            (
+                STROKE_WIDTH_KEYS,
+                self.stroke_width.map(|width| width.to_string()),
+            ),
+        ];
+
+        for (keys, value) in fields {
+            for key in keys {
+                metadata.remove(*key);
+            }
+
+            if let Some(value) = value {
+                metadata.insert(keys[0].to_string(), value);
+            }
+        }
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        [
+            ("Name", &self.name),
+            ("Family", &self.family),
+            ("Source", &self.source),
+            ("License", &self.license),
+        ]
+        .iter()
+        .filter_map(|(label, value)| Some(format!("{}: {}", label, value.as_ref()?)))
+        .chain(
+            self.stroke_width
+                .map(|width| format!("Stroke width: {}", width)),
+        )
+        .collect()
+    }
+}
+
+impl HersheyFont {
+    pub fn meta(&self) -> Option<FontMeta> {
+        FontMeta::from_metadata(&self.metadata)
+    }
+
+    pub fn with_meta(mut self, meta: FontMeta) -> HersheyFont {
+        meta.write_metadata(&mut self.metadata);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_populates_meta_from_headers() {
+        let font =
+            HersheyFont::new("name: futural\nlicense: public domain\n  720  3G][BIb").unwrap();
+
+        assert_eq!(
+            font.meta(),
+            Some(FontMeta {
+                name: Some("futural".to_string()),
+                family: None,
+                source: None,
+                license: Some("public domain".to_string()),
+                stroke_width: None,
+            })
+        );
+    }
+
+    #[test]
+    fn new_leaves_meta_empty_without_headers() {
+        let font = HersheyFont::new("  720  3G][BIb").unwrap();
+
+        assert_eq!(font.meta(), None);
+    }
+
+    #[test]
+    fn with_meta_updates_metadata() {
+        let font = HersheyFont::new("licence: old\n  720  3G][BIb")
+            .unwrap()
+            .with_meta(FontMeta {
+                name: Some("rowmans".to_string()),
+                license: Some("public domain".to_string()),
+                ..FontMeta::default()
+            });
+
+        assert_eq!(
+            font.metadata.get("name").map(String::as_str),
+            Some("rowmans")
+        );
+        assert_eq!(font.metadata.get("licence"), None);
+        assert_eq!(
+            font.meta().and_then(|meta| meta.license).as_deref(),
+            Some("public domain")
+        );
+    }
+
+    #[test]
+    fn lines_works() {
+        let meta = FontMeta {
+            name: Some("rowmans".to_string()),
+            source: Some("rowmans.jhf".to_string()),
+            ..FontMeta::default()
+        };
+
+        assert_eq!(meta.lines(), vec!["Name: rowmans", "Source: rowmans.jhf"]);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{HersheyFont, HersheyGlyph};
+
+impl HersheyGlyph {
+    pub fn advance(&self) -> i32 {
+        self.right - self.left
+    }
+
+    fn ink_x_range(&self) -> Option<(i32, i32)> {
+        self.paths.iter().flatten().fold(None, |accum, edge| {
+            Some(match accum {
+                Some((min, max)) => (edge.x.min(min), edge.x.max(max)),
+                None => (edge.x, edge.x),
+            })
+        })
+    }
+
+    pub fn left_side_bearing(&self) -> Option<i32> {
+        self.ink_x_range().map(|(min, _)| min - self.left)
+    }
+
+    pub fn right_side_bearing(&self) -> Option<i32> {
+        self.ink_x_range().map(|(_, max)| self.right - max)
+    }
+}
+
+impl HersheyFont {
+    fn glyph_top(&self, c: char) -> Option<i32> {
+        self.get_glyph_checked(c)
+            .filter(|glyph| !glyph.paths.is_empty())
+            .map(|glyph| glyph.top)
+    }
+
+    pub fn cap_height(&self) -> Option<i32> {
+        self.glyph_top('H').map(|top| self.baseline() - top)
+    }
+
+    pub fn x_height(&self) -> Option<i32> {
+        self.glyph_top('x').map(|top| self.baseline() - top)
+    }
+
+    pub fn ascent(&self) -> i32 {
+        ['d', 'h', 'b', 'H']
+            .iter()
+            .find_map(|c| self.glyph_top(*c))
+            .map_or(self.baseline() - self.top, |top| self.baseline() - top)
+    }
+
+    pub fn descent(&self) -> i32 {
+        ['p', 'q', 'g']
+            .iter()
+            .find_map(|c| {
+                self.get_glyph_checked(*c)
+                    .filter(|glyph| !glyph.paths.is_empty())
+            })
+            .map_or(self.bottom - self.baseline(), |glyph| {
+                glyph.bottom - self.baseline()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::HersheyFontBuilder;
+    use crate::Edge;
+
+    fn line(top: i32, bottom: i32) -> HersheyGlyph {
+        HersheyGlyph::new(
+            -6,
+            6,
+            vec![vec![Edge { x: -2, y: top }, Edge { x: 3, y: bottom }]],
+        )
+    }
+
+    #[test]
+    fn glyph_metrics_work() {
+        let glyph = line(-12, 9);
+
+        assert_eq!(glyph.advance(), 12);
+        assert_eq!(glyph.left_side_bearing(), Some(4));
+        assert_eq!(glyph.right_side_bearing(), Some(3));
+        assert_eq!(
+            HersheyGlyph::new(-4, 4, Vec::new()).left_side_bearing(),
+            None
+        );
+    }
+
+    #[test]
+    fn font_metrics_work() {
+        let font = HersheyFontBuilder::new()
+            .glyph('H', line(-12, 9))
+            .glyph('x', line(-5, 9))
+            .glyph('d', line(-13, 9))
+            .glyph('p', line(-5, 16))
+            .build()
+            .unwrap();
+
+        assert_eq!(font.cap_height(), Some(21));
+        assert_eq!(font.x_height(), Some(14));
+        assert_eq!(font.ascent(), 22);
+        assert_eq!(font.descent(), 7);
+    }
+}
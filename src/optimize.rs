@@ -0,0 +1,203 @@
+use crate::layout::TextLayout;
+use crate::Edge;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TravelOptions {
+    pub start: Option<Edge>,
+    pub allow_reverse: bool,
+    pub two_opt: bool,
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for TravelOptions {
+    fn default() -> TravelOptions {
+        TravelOptions {
+            start: None,
+            allow_reverse: true,
+            two_opt: true,
+            time_budget: Some(Duration::from_millis(500)),
+        }
+    }
+}
+
+pub fn travel_distance(paths: &[Vec<Edge>], start: Option<Edge>) -> f64 {
+    let mut position = start;
+    let mut distance = 0.0;
+
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        if let Some(position) = position {
+            distance += position.distance_to(&path[0]);
+        }
+
+        position = path.last().copied();
+    }
+
+    distance
+}
+
+fn nearest_neighbor(paths: Vec<Vec<Edge>>, options: &TravelOptions) -> Vec<Vec<Edge>> {
+    let mut remaining = paths;
+    let mut ordered = Vec::with_capacity(remaining.len());
+    let mut position = options.start;
+
+    while !remaining.is_empty() {
+        let Some(current) = position else {
+            let first = remaining.remove(0);
+            position = first.last().copied();
+            ordered.push(first);
+            continue;
+        };
+
+        let (index, reversed, _) = remaining
+            .iter()
+            .enumerate()
+            .flat_map(|(i, path)| {
+                let forward = Some((i, false, current.distance_to(&path[0])));
+                let backward = options
+                    .allow_reverse
+                    .then(|| (i, true, current.distance_to(path.last().unwrap())));
+
+                forward.into_iter().chain(backward)
+            })
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .unwrap();
+
+        let mut path = remaining.remove(index);
+
+        if reversed {
+            path.reverse();
+        }
+
+        position = path.last().copied();
+        ordered.push(path);
+    }
+
+    ordered
+}
+
+fn two_opt(paths: &mut [Vec<Edge>], options: &TravelOptions) {
+    let started = Instant::now();
+    let start_of = |path: &Vec<Edge>| path[0];
+    let end_of = |path: &Vec<Edge>| *path.last().unwrap();
+
+    let mut improved = true;
+
+    while improved {
+        improved = false;
+
+        for i in 0..paths.len() {
+            for j in i + 1..paths.len() {
+                if options
+                    .time_budget
+                    .is_some_and(|budget| started.elapsed() > budget)
+                {
+                    return;
+                }
+
+                let before_end = if i == 0 {
+                    options.start
+                } else {
+                    Some(end_of(&paths[i - 1]))
+                };
+                let after_start = paths.get(j + 1).map(start_of);
+
+                let distance = |a: Option<Edge>, b: Option<Edge>| match (a, b) {
+                    (Some(a), Some(b)) => a.distance_to(&b),
+                    _ => 0.0,
+                };
+
+                let current = distance(before_end, Some(start_of(&paths[i])))
+                    + distance(Some(end_of(&paths[j])), after_start);
+                let candidate = distance(before_end, Some(end_of(&paths[j])))
+                    + distance(Some(start_of(&paths[i])), after_start);
+
+                if candidate + 1e-9 < current {
+                    paths[i..=j].reverse();
+                    paths[i..=j].iter_mut().for_each(|path| path.reverse());
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+pub fn optimize_travel(paths: &[Vec<Edge>], options: &TravelOptions) -> Vec<Vec<Edge>> {
+    let paths = paths
+        .iter()
+        .filter(|path| !path.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut ordered = nearest_neighbor(paths, options);
+
+    if options.two_opt && options.allow_reverse {
+        two_opt(&mut ordered, options);
+    }
+
+    ordered
+}
+
+impl TextLayout {
+    pub fn optimize_travel(&self) -> Vec<Vec<Edge>> {
+        self.optimize_travel_with_options(&TravelOptions::default())
+    }
+
+    pub fn optimize_travel_with_options(&self, options: &TravelOptions) -> Vec<Vec<Edge>> {
+        optimize_travel(&self.paths(), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HersheyFont;
+
+    fn segment(x0: i32, x1: i32) -> Vec<Edge> {
+        vec![Edge { x: x0, y: 0 }, Edge { x: x1, y: 0 }]
+    }
+
+    #[test]
+    fn optimize_travel_reduces_travel() {
+        let paths = vec![segment(0, 1), segment(10, 11), segment(2, 3), segment(8, 9)];
+        let optimized = optimize_travel(&paths, &TravelOptions::default());
+
+        assert!(travel_distance(&optimized, None) < travel_distance(&paths, None));
+        assert_eq!(
+            optimized,
+            vec![segment(0, 1), segment(2, 3), segment(8, 9), segment(10, 11)]
+        );
+    }
+
+    #[test]
+    fn optimize_travel_reverses_paths() {
+        let paths = vec![segment(0, 1), segment(5, 2)];
+        let optimized = optimize_travel(
+            &paths,
+            &TravelOptions {
+                two_opt: false,
+                ..TravelOptions::default()
+            },
+        );
+
+        assert_eq!(optimized, vec![segment(0, 1), segment(2, 5)]);
+    }
+
+    #[test]
+    fn travel_distance_works() {
+        let paths = vec![segment(0, 1), segment(3, 4)];
+
+        assert_eq!(travel_distance(&paths, Some(Edge { x: 0, y: 0 })), 2.0);
+    }
+
+    #[test]
+    fn text_layout_optimize_travel_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let layout = font.layout_text("!!!");
+        let optimized = layout.optimize_travel();
+
+        assert_eq!(optimized.len(), 3);
+        assert!(travel_distance(&optimized, None) < travel_distance(&layout.paths(), None));
+        assert_eq!(optimized[1][0], Edge { x: 15, y: 10 });
+    }
+}
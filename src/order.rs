@@ -0,0 +1,81 @@
+use crate::bounds::{edges_bounds, Bounds};
+use crate::{Edge, HersheyGlyph};
+use std::cmp::Ordering;
+
+fn encloses(outer: &Bounds, inner: &Bounds) -> bool {
+    outer != inner
+        && outer.left <= inner.left
+        && outer.right >= inner.right
+        && outer.top <= inner.top
+        && outer.bottom >= inner.bottom
+}
+
+pub fn sort_inside_out(paths: &mut [Vec<Edge>]) {
+    let bounds = paths
+        .iter()
+        .map(|path| edges_bounds(path.iter()))
+        .collect::<Vec<_>>();
+
+    let mut keys = bounds
+        .iter()
+        .enumerate()
+        .map(|(i, path_bounds)| {
+            let depth = match path_bounds {
+                Some(path_bounds) => bounds
+                    .iter()
+                    .flatten()
+                    .filter(|other| encloses(other, path_bounds))
+                    .count(),
+                None => 0,
+            };
+            let area = path_bounds.map_or(0.0, |b| b.width() * b.height());
+
+            (depth, area, i)
+        })
+        .collect::<Vec<_>>();
+
+    keys.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then(a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            .then(a.2.cmp(&b.2))
+    });
+
+    let sorted = keys
+        .iter()
+        .map(|(_, _, i)| paths[*i].clone())
+        .collect::<Vec<_>>();
+
+    paths.clone_from_slice(&sorted);
+}
+
+impl HersheyGlyph {
+    pub fn sort_inside_out(&mut self) {
+        sort_inside_out(&mut self.paths);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HersheyFont;
+
+    #[test]
+    fn sort_inside_out_works() {
+        let font =
+            HersheyFont::new(r"  714 24JZHH\H\\H\HH RMMWMWWMWMM Rffgfggfgff RQQSQSSQSQQ").unwrap();
+        let squares = font.get_glyph(' ').unwrap().paths.clone();
+        let mut paths = squares.clone();
+
+        sort_inside_out(&mut paths);
+
+        assert_eq!(
+            paths,
+            vec![
+                squares[3].clone(),
+                squares[1].clone(),
+                squares[2].clone(),
+                squares[0].clone()
+            ]
+        );
+    }
+}
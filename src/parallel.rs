@@ -0,0 +1,73 @@
+use crate::error::ParseError;
+use crate::{
+    record_to_hershey_glyph, split_records, HersheyFont, HersheyFontNewError, ParseOptions,
+};
+use std::num::NonZeroUsize;
+use std::thread;
+
+pub(crate) fn thread_count(jobs: usize) -> usize {
+    thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(jobs)
+        .max(1)
+}
+
+impl HersheyFont {
+    pub fn new_parallel(data: &str) -> Result<HersheyFont, HersheyFontNewError> {
+        HersheyFont::new_parallel_with_options(data, &ParseOptions::default())
+    }
+
+    pub fn new_parallel_with_options(
+        data: &str,
+        options: &ParseOptions,
+    ) -> Result<HersheyFont, HersheyFontNewError> {
+        let (metadata, records) = split_records(data);
+        let chunk_size = records.len().div_ceil(thread_count(records.len())).max(1);
+
+        let chunks = thread::scope(|scope| {
+            records
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|record| record_to_hershey_glyph(record, options))
+                            .collect::<Result<Vec<_>, ParseError>>()
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|handle| handle.join().expect("glyph parsing thread panicked"))
+                .collect::<Result<Vec<_>, ParseError>>()
+        })?;
+
+        Ok(HersheyFont::from_parts(metadata, chunks.concat()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_parallel_matches_new() {
+        let data = "name: test\n".to_string() + &"  720  3MWRHR\\\n".repeat(100);
+
+        assert_eq!(
+            HersheyFont::new_parallel(&data).unwrap(),
+            HersheyFont::new(&data).unwrap()
+        );
+    }
+
+    #[test]
+    fn new_parallel_reports_first_error() {
+        let data =
+            "  720  3MWRHR\\\n".repeat(50) + "  720  9MWRHR\\\n" + &"  720  1JZ\n".repeat(50);
+        let result = HersheyFont::new_parallel(&data);
+
+        assert!(matches!(
+            result,
+            Err(HersheyFontNewError::ParseError(ParseError { line: 51, .. }))
+        ));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::gcode::format_number;
+use crate::layout::TextLayout;
+use crate::meta::FontMeta;
+use crate::units::Units;
+use crate::{Edge, HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PdfOptions {
+    pub scale: f64,
+    pub stroke_width: f64,
+    pub padding: f64,
+    pub flip_y: bool,
+    pub units: Option<Units>,
+    pub meta: Option<FontMeta>,
+}
+
+impl Default for PdfOptions {
+    fn default() -> PdfOptions {
+        PdfOptions {
+            scale: 1.0,
+            stroke_width: 1.0,
+            padding: 0.0,
+            flip_y: true,
+            units: None,
+            meta: None,
+        }
+    }
+}
+
+const POINTS_PER_INCH: f64 = 72.0;
+
+fn text_string(text: &str) -> String {
+    let hex = text
+        .encode_utf16()
+        .map(|unit| format!("{:04X}", unit))
+        .collect::<String>();
+
+    format!("<FEFF{}>", hex)
+}
+
+fn info_dictionary(meta: &FontMeta) -> String {
+    let entries = [
+        ("Title", &meta.name),
+        ("Family", &meta.family),
+        ("Source", &meta.source),
+        ("License", &meta.license),
+    ]
+    .iter()
+    .filter_map(|(key, value)| Some(format!("/{} {}", key, text_string(value.as_ref()?))))
+    .collect::<Vec<_>>();
+
+    format!(
+        "<< /Producer {} {} >>",
+        text_string("hershey-parser"),
+        entries.join(" ")
+    )
+}
+
+pub fn write_pdf_points(paths: &[Vec<PointF>], options: &PdfOptions) -> String {
+    let scale = options.scale
+        * options
+            .units
+            .map_or(1.0, |units| units.factor(Units::Inches) * POINTS_PER_INCH);
+    let to_page = |point: &PointF| PointF {
+        x: point.x * scale,
+        y: point.y * scale * if options.flip_y { -1.0 } else { 1.0 },
+    };
+    let margin = options.padding + options.stroke_width / 2.0;
+
+    let (left, bottom, right, top) = paths
+        .iter()
+        .flatten()
+        .map(to_page)
+        .fold(None, |accum: Option<(f64, f64, f64, f64)>, point| {
+            Some(match accum {
+                Some((left, bottom, right, top)) => (
+                    left.min(point.x),
+                    bottom.min(point.y),
+                    right.max(point.x),
+                    top.max(point.y),
+                ),
+                None => (point.x, point.y, point.x, point.y),
+            })
+        })
+        .map_or((0.0, 0.0, 0.0, 0.0), |(left, bottom, right, top)| {
+            (left - margin, bottom - margin, right + margin, top + margin)
+        });
+
+    let mut content = vec![
+        format!("{} w", format_number(options.stroke_width)),
+        "1 J".to_string(),
+        "1 j".to_string(),
+    ];
+
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        let start = to_page(&path[0]);
+        let mut commands = vec![format!(
+            "{} {} m",
+            format_number(start.x),
+            format_number(start.y)
+        )];
+        let rest = if path.len() == 1 { path } else { &path[1..] };
+
+        commands.extend(rest.iter().map(|point| {
+            let point = to_page(point);
+
+            format!("{} {} l", format_number(point.x), format_number(point.y))
+        }));
+        commands.push("S".to_string());
+        content.push(commands.join(" "));
+    }
+
+    let content = content.join("\n");
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [{} {} {} {}] /Contents 4 0 R >>",
+            format_number(left),
+            format_number(bottom),
+            format_number(right),
+            format_number(top)
+        ),
+        format!(
+            "<< /Length {} >>\nstream\n{}\nendstream",
+            content.len(),
+            content
+        ),
+    ];
+
+    if let Some(meta) = &options.meta {
+        objects.push(info_dictionary(meta));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::new();
+
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+    }
+
+    let xref = pdf.len();
+
+    pdf.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+
+    for offset in offsets {
+        pdf.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+
+    let info = if options.meta.is_some() {
+        format!(" /Info {} 0 R", objects.len())
+    } else {
+        String::new()
+    };
+
+    pdf.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R{} >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        info,
+        xref
+    ));
+    pdf
+}
+
+pub fn write_pdf(paths: &[Vec<Edge>], options: &PdfOptions) -> String {
+    let points = paths
+        .iter()
+        .map(|path| path.iter().copied().map(PointF::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    write_pdf_points(&points, options)
+}
+
+impl HersheyGlyph {
+    pub fn to_pdf(&self, options: &PdfOptions) -> String {
+        write_pdf(&self.paths, options)
+    }
+}
+
+impl TextLayout {
+    pub fn to_pdf(&self, options: &PdfOptions) -> String {
+        write_pdf(&self.paths(), options)
+    }
+}
+
+impl HersheyFont {
+    pub fn text_to_pdf(&self, text: &str, options: &PdfOptions) -> String {
+        let mut options = options.clone();
+
+        if options.meta.is_none() {
+            options.meta = self.meta();
+        }
+
+        options.flip_y = options.flip_y != self.y_up();
+        self.layout_text(text).to_pdf(&options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+
+    #[test]
+    fn write_pdf_works() {
+        let paths = vec![vec![Edge { x: 0, y: -10 }, Edge { x: 5, y: 10 }]];
+        let pdf = write_pdf(
+            &paths,
+            &PdfOptions {
+                scale: 0.5,
+                stroke_width: 0.5,
+                ..PdfOptions::default()
+            },
+        );
+
+        assert!(pdf.starts_with("%PDF-1.4\n1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n"));
+        assert!(pdf.contains("/MediaBox [-0.25 -5.25 2.75 5.25]"));
+        assert!(pdf.contains("stream\n0.5 w\n1 J\n1 j\n0 5 m 2.5 -5 l S\nendstream"));
+        assert!(pdf.contains("trailer\n<< /Size 5 /Root 1 0 R >>"));
+        assert!(pdf.ends_with("%%EOF\n"));
+    }
+
+    #[test]
+    fn write_pdf_xref_points_at_objects() {
+        let pdf = write_pdf(&[], &PdfOptions::default());
+        let xref = pdf[pdf.find("startxref\n").unwrap() + 10..]
+            .lines()
+            .next()
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+
+        assert!(pdf[xref..].starts_with("xref\n0 5\n"));
+
+        for (i, line) in pdf[xref..].lines().skip(3).take(4).enumerate() {
+            let offset = line[..10].parse::<usize>().unwrap();
+
+            assert_eq!(line.len(), 19);
+            assert!(pdf[offset..].starts_with(&format!("{} 0 obj\n", i + 1)));
+        }
+    }
+
+    #[test]
+    fn text_to_pdf_embeds_metadata() {
+        let font = HersheyFont::new("name: Fu(tu)ral\nlicense: free\n  720  1JZ\n  720  3MWRHR\\")
+            .unwrap();
+        let pdf = font.text_to_pdf("!!", &PdfOptions::default());
+
+        assert!(pdf.contains("/Title <FEFF00460075002800740075002900720061006C>"));
+        assert!(pdf.contains("/License <FEFF0066007200650065>"));
+        assert!(pdf.contains("/Size 6 /Root 1 0 R /Info 5 0 R"));
+        assert_eq!(pdf.matches(" S\n").count(), 2);
+    }
+
+    #[test]
+    fn text_to_pdf_follows_coordinate_system() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHRR").unwrap();
+        let mut y_up = font.clone();
+        y_up.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            y_up.text_to_pdf("!\n!", &PdfOptions::default()),
+            font.text_to_pdf("!\n!", &PdfOptions::default())
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Edge, HersheyGlyph};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MotionProfile {
+    pub draw_speed: f64,
+    pub travel_speed: f64,
+    pub acceleration: f64,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PlotTimeEstimateError {
+    #[error("{0}")]
+    InvalidProfile(String),
+}
+
+fn move_time(distance: f64, speed: f64, acceleration: f64) -> f64 {
+    if distance <= 0.0 {
+        return 0.0;
+    }
+
+    let ramp_distance = speed * speed / acceleration;
+
+    if distance >= ramp_distance {
+        distance / speed + speed / acceleration
+    } else {
+        2.0 * (distance / acceleration).sqrt()
+    }
+}
+
+pub fn estimate_plot_time(
+    paths: &[Vec<Edge>],
+    scale: f64,
+    profile: &MotionProfile,
+) -> Result<Duration, PlotTimeEstimateError> {
+    for (name, speed) in [
+        ("draw", profile.draw_speed),
+        ("travel", profile.travel_speed),
+    ] {
+        if !(speed.is_finite() && speed > 0.0) {
+            return Err(PlotTimeEstimateError::InvalidProfile(format!(
+                "The {} speed must be positive, got {}",
+                name, speed
+            )));
+        }
+    }
+
+    if !(profile.acceleration.is_finite() && profile.acceleration > 0.0) {
+        return Err(PlotTimeEstimateError::InvalidProfile(format!(
+            "The acceleration must be positive, got {}",
+            profile.acceleration
+        )));
+    }
+
+    let mut seconds = 0.0;
+    let mut position: Option<Edge> = None;
+
+    for path in paths.iter().filter(|path| !path.is_empty()) {
+        if let Some(position) = position {
+            seconds += move_time(
+                position.distance_to(&path[0]) * scale,
+                profile.travel_speed,
+                profile.acceleration,
+            );
+        }
+
+        for pair in path.windows(2) {
+            seconds += move_time(
+                pair[0].distance_to(&pair[1]) * scale,
+                profile.draw_speed,
+                profile.acceleration,
+            );
+        }
+
+        position = path.last().copied();
+    }
+
+    Duration::try_from_secs_f64(seconds).map_err(|error| {
+        PlotTimeEstimateError::InvalidProfile(format!(
+            "Invalid plot time of {} seconds: {}",
+            seconds, error
+        ))
+    })
+}
+
+impl HersheyGlyph {
+    pub fn estimate_plot_time(
+        &self,
+        scale: f64,
+        profile: &MotionProfile,
+    ) -> Result<Duration, PlotTimeEstimateError> {
+        estimate_plot_time(&self.paths, scale, profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_plot_time_works() {
+        let paths = vec![
+            vec![Edge { x: 0, y: 0 }, Edge { x: 10, y: 0 }],
+            vec![Edge { x: 10, y: 10 }, Edge { x: 0, y: 10 }],
+        ];
+        let profile = MotionProfile {
+            draw_speed: 5.0,
+            travel_speed: 10.0,
+            acceleration: 10.0,
+        };
+
+        assert_eq!(
+            estimate_plot_time(&paths, 1.0, &profile).unwrap(),
+            Duration::from_secs(7)
+        );
+    }
+
+    #[test]
+    fn estimate_plot_time_rejects_non_positive_speeds() {
+        let paths = vec![vec![Edge { x: 0, y: 0 }, Edge { x: 10, y: 0 }]];
+        let profile = MotionProfile {
+            draw_speed: 0.0,
+            travel_speed: 10.0,
+            acceleration: 10.0,
+        };
+
+        assert!(matches!(
+            estimate_plot_time(&paths, 1.0, &profile),
+            Err(PlotTimeEstimateError::InvalidProfile(_))
+        ));
+    }
+
+    #[test]
+    fn estimate_plot_time_rejects_invalid_acceleration() {
+        let paths = vec![vec![Edge { x: 0, y: 0 }, Edge { x: 10, y: 0 }]];
+
+        for acceleration in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let profile = MotionProfile {
+                draw_speed: 5.0,
+                travel_speed: 10.0,
+                acceleration,
+            };
+
+            assert!(matches!(
+                estimate_plot_time(&paths, 1.0, &profile),
+                Err(PlotTimeEstimateError::InvalidProfile(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn move_time_accounts_for_acceleration() {
+        assert_eq!(move_time(10.0, 5.0, 5.0), 3.0);
+        assert_eq!(move_time(1.25, 5.0, 5.0), 1.0);
+    }
+}
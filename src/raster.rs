@@ -0,0 +1,232 @@
+use crate::bounds::edges_bounds;
+use crate::layout::TextLayout;
+use crate::{Edge, HersheyGlyph};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+    pub origin: (f64, f64),
+    pub pixels: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RasterOptions {
+    pub scale: f64,
+    pub stroke_width: f64,
+    pub antialias: bool,
+    pub supersampling: usize,
+}
+
+impl Default for RasterOptions {
+    fn default() -> RasterOptions {
+        RasterOptions {
+            scale: 1.0,
+            stroke_width: 1.0,
+            antialias: true,
+            supersampling: 1,
+        }
+    }
+}
+
+impl Bitmap {
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+}
+
+pub(crate) fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+
+    (p.0 - (a.0 + t * dx)).hypot(p.1 - (a.1 + t * dy))
+}
+
+fn sample_coverage(distance: f64, radius: f64, antialias: bool) -> f64 {
+    if antialias {
+        (radius + 0.5 - distance).clamp(0.0, 1.0)
+    } else if distance <= radius {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+pub fn rasterize_paths(paths: &[Vec<Edge>], options: &RasterOptions) -> Bitmap {
+    let radius = options.stroke_width * options.scale / 2.0;
+    let padding = (radius + 1.0).ceil();
+
+    let Some(bounds) = edges_bounds(paths.iter().flatten()) else {
+        return Bitmap {
+            width: 0,
+            height: 0,
+            origin: (0.0, 0.0),
+            pixels: Vec::new(),
+        };
+    };
+
+    let origin = (
+        padding - bounds.left * options.scale,
+        padding - bounds.top * options.scale,
+    );
+    let width = (bounds.width() * options.scale + 2.0 * padding).ceil() as usize;
+    let height = (bounds.height() * options.scale + 2.0 * padding).ceil() as usize;
+    let samples = options.supersampling.max(1);
+    let mut coverage = vec![0.0f64; width * height];
+
+    let to_pixel = |edge: &Edge| {
+        (
+            origin.0 + edge.x as f64 * options.scale,
+            origin.1 + edge.y as f64 * options.scale,
+        )
+    };
+
+    for path in paths {
+        let points = path.iter().map(to_pixel).collect::<Vec<_>>();
+        let segments = match points.len() {
+            1 => vec![(points[0], points[0])],
+            _ => points.windows(2).map(|pair| (pair[0], pair[1])).collect(),
+        };
+
+        for (a, b) in segments {
+            let min_x = ((a.0.min(b.0) - radius - 1.0).floor().max(0.0)) as usize;
+            let max_x = ((a.0.max(b.0) + radius + 1.0).ceil() as usize).min(width);
+            let min_y = ((a.1.min(b.1) - radius - 1.0).floor().max(0.0)) as usize;
+            let max_y = ((a.1.max(b.1) + radius + 1.0).ceil() as usize).min(height);
+
+            for y in min_y..max_y {
+                for x in min_x..max_x {
+                    let mut total = 0.0;
+
+                    for sy in 0..samples {
+                        for sx in 0..samples {
+                            let p = (
+                                x as f64 + (sx as f64 + 0.5) / samples as f64,
+                                y as f64 + (sy as f64 + 0.5) / samples as f64,
+                            );
+
+                            total += sample_coverage(
+                                segment_distance(p, a, b),
+                                radius,
+                                options.antialias,
+                            );
+                        }
+                    }
+
+                    let pixel = &mut coverage[y * width + x];
+                    *pixel = pixel.max(total / (samples * samples) as f64);
+                }
+            }
+        }
+    }
+
+    Bitmap {
+        width,
+        height,
+        origin,
+        pixels: coverage
+            .into_iter()
+            .map(|c| (c * 255.0).round() as u8)
+            .collect(),
+    }
+}
+
+impl HersheyGlyph {
+    pub fn rasterize(&self, scale: f64, stroke_width: f64) -> Bitmap {
+        self.rasterize_with(&RasterOptions {
+            scale,
+            stroke_width,
+            ..RasterOptions::default()
+        })
+    }
+
+    pub fn rasterize_with(&self, options: &RasterOptions) -> Bitmap {
+        rasterize_paths(&self.paths, options)
+    }
+}
+
+impl TextLayout {
+    pub fn rasterize(&self, options: &RasterOptions) -> Bitmap {
+        rasterize_paths(&self.paths(), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HersheyFont;
+
+    fn line() -> Vec<Vec<Edge>> {
+        vec![vec![Edge { x: 0, y: 0 }, Edge { x: 10, y: 0 }]]
+    }
+
+    #[test]
+    fn rasterize_paths_works() {
+        let bitmap = rasterize_paths(
+            &line(),
+            &RasterOptions {
+                antialias: false,
+                ..RasterOptions::default()
+            },
+        );
+
+        assert_eq!(bitmap.origin, (2.0, 2.0));
+        assert_eq!(bitmap.get(5, 0), Some(0));
+        assert_eq!(bitmap.get(5, 1), Some(255));
+        assert!(bitmap.pixels.iter().all(|p| *p == 0 || *p == 255));
+    }
+
+    #[test]
+    fn rasterize_paths_antialiases_edges() {
+        let bitmap = rasterize_paths(
+            &line(),
+            &RasterOptions {
+                stroke_width: 1.5,
+                supersampling: 4,
+                ..RasterOptions::default()
+            },
+        );
+
+        assert!(bitmap.pixels.iter().any(|p| *p > 0 && *p < 255));
+    }
+
+    #[test]
+    fn rasterize_paths_handles_empty_input() {
+        let bitmap = rasterize_paths(&[], &RasterOptions::default());
+
+        assert_eq!(bitmap.pixels.len(), 0);
+    }
+
+    #[test]
+    fn glyph_and_layout_rasterize_work() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let glyph = font.get_glyph('!').unwrap();
+        let bitmap = glyph.rasterize(0.5, 2.0);
+
+        assert_eq!(
+            bitmap,
+            rasterize_paths(
+                &glyph.paths,
+                &RasterOptions {
+                    scale: 0.5,
+                    stroke_width: 2.0,
+                    ..RasterOptions::default()
+                }
+            )
+        );
+        assert_eq!((bitmap.width, bitmap.height), (4, 14));
+
+        let layout = font.layout_text("!!").rasterize(&RasterOptions::default());
+
+        assert_eq!(layout.width, 14);
+    }
+}
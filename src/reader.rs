@@ -0,0 +1,139 @@
+use crate::error::ParseError;
+use crate::{
+    is_wrapped, line_to_metadata, record_to_hershey_glyph, HersheyFont, HersheyGlyph, ParseOptions,
+    RecordLine,
+};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyFontFromReaderError {
+    #[error("{1}")]
+    IoError(#[source] std::io::Error, String),
+    #[error("{source} at byte {offset}")]
+    ParseError { source: ParseError, offset: usize },
+}
+
+fn decode(
+    record: &RecordLine,
+    options: &ParseOptions,
+) -> Result<HersheyGlyph, HersheyFontFromReaderError> {
+    record_to_hershey_glyph(record, options).map_err(|source| {
+        HersheyFontFromReaderError::ParseError {
+            source,
+            offset: record.span.start,
+        }
+    })
+}
+
+impl HersheyFont {
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<HersheyFont, HersheyFontFromReaderError> {
+        HersheyFont::from_reader_with_options(reader, &ParseOptions::default())
+    }
+
+    pub fn from_reader_with_options<R: BufRead>(
+        mut reader: R,
+        options: &ParseOptions,
+    ) -> Result<HersheyFont, HersheyFontFromReaderError> {
+        let mut metadata = BTreeMap::new();
+        let mut glyphs = Vec::new();
+        let mut pending: Option<RecordLine<'static>> = None;
+        let mut buffer = String::new();
+        let mut offset = 0;
+
+        for i in 0.. {
+            buffer.clear();
+
+            let read = reader.read_line(&mut buffer).map_err(|e| {
+                HersheyFontFromReaderError::IoError(e, format!("Error reading line {}", i + 1))
+            })?;
+
+            if read == 0 {
+                break;
+            }
+
+            let line_offset = offset;
+            let line = buffer
+                .strip_suffix('\n')
+                .unwrap_or(&buffer)
+                .trim_end_matches('\r');
+
+            offset += read;
+
+            if let Some(record) = pending.as_mut().filter(|record| is_wrapped(record)) {
+                record.text.to_mut().push_str(line);
+                record.span.end = line_offset + line.len();
+                continue;
+            }
+
+            if line.is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            if pending.is_none() && glyphs.is_empty() {
+                if let Some((key, value)) = line_to_metadata(line) {
+                    metadata.insert(key, value);
+                    continue;
+                }
+            }
+
+            if let Some(record) = pending.take() {
+                glyphs.push(decode(&record, options)?);
+            }
+
+            pending = Some(RecordLine {
+                line: i,
+                span: line_offset..line_offset + line.len(),
+                text: Cow::Owned(line.to_string()),
+            });
+        }
+
+        if let Some(record) = pending {
+            glyphs.push(decode(&record, options)?);
+        }
+
+        Ok(HersheyFont::from_parts(metadata, glyphs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_reader_matches_new() {
+        let data = "name: test\r\n  720  1JZ\r\n# comment\r\n\r\n  720  3G][BIb\r\n";
+        let font = HersheyFont::from_reader(data.as_bytes()).unwrap();
+
+        assert_eq!(font, HersheyFont::new(&data.replace('\r', "")).unwrap());
+    }
+
+    #[test]
+    fn from_reader_reports_line_and_byte_offset() {
+        let data = "  720  1JZ\n  720  3G][Bb\n";
+        let error = HersheyFont::from_reader(data.as_bytes()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Error parsing line 2: declared 3 vertices but found 2 at byte 11"
+        );
+        assert!(matches!(
+            error,
+            HersheyFontFromReaderError::ParseError {
+                source: ParseError { line: 2, .. },
+                offset: 11,
+            }
+        ));
+    }
+
+    #[test]
+    fn from_reader_returns_error_for_invalid_utf8() {
+        let result = HersheyFont::from_reader(&b"  720  1JZ\n\xff\n"[..]);
+
+        assert!(matches!(
+            result,
+            Err(HersheyFontFromReaderError::IoError(..))
+        ));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::optimize::travel_distance;
+use crate::stats::path_length;
+use crate::Edge;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct JobReport {
+    pub draw_distance: f64,
+    pub travel_distance: f64,
+    pub pen_lifts: usize,
+    pub segment_count: usize,
+}
+
+impl JobReport {
+    pub fn from_paths(paths: &[Vec<Edge>], start: Option<Edge>) -> JobReport {
+        JobReport {
+            draw_distance: paths.iter().map(|path| path_length(path)).sum(),
+            travel_distance: travel_distance(paths, start),
+            pen_lifts: paths.iter().filter(|path| !path.is_empty()).count(),
+            segment_count: paths.iter().map(|path| path.len().saturating_sub(1)).sum(),
+        }
+    }
+
+    pub fn scaled(&self, scale: f64) -> JobReport {
+        JobReport {
+            draw_distance: self.draw_distance * scale,
+            travel_distance: self.travel_distance * scale,
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_paths_works() {
+        let paths = vec![
+            vec![
+                Edge { x: 0, y: 0 },
+                Edge { x: 3, y: 4 },
+                Edge { x: 3, y: 8 },
+            ],
+            vec![],
+            vec![Edge { x: 3, y: 10 }, Edge { x: 3, y: 11 }],
+        ];
+
+        assert_eq!(
+            JobReport::from_paths(&paths, None),
+            JobReport {
+                draw_distance: 10.0,
+                travel_distance: 2.0,
+                pen_lifts: 2,
+                segment_count: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn scaled_works() {
+        let report = JobReport {
+            draw_distance: 10.0,
+            travel_distance: 2.0,
+            pen_lifts: 2,
+            segment_count: 3,
+        }
+        .scaled(0.5);
+
+        assert_eq!(report.draw_distance, 5.0);
+        assert_eq!(report.travel_distance, 1.0);
+        assert_eq!(report.pen_lifts, 2);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::text::font_height;
+use crate::{HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SizeReference {
+    #[default]
+    FontHeight,
+    CapHeight,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaledFont<'a> {
+    font: &'a HersheyFont,
+    pub scale: f64,
+    baseline: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScaledGlyph {
+    pub advance: f64,
+    pub top: f64,
+    pub bottom: f64,
+    pub paths: Vec<Vec<PointF>>,
+}
+
+impl HersheyFont {
+    pub fn scaled(&self, size: f64, reference: SizeReference) -> ScaledFont<'_> {
+        let height = match reference {
+            SizeReference::FontHeight => font_height(self),
+            SizeReference::CapHeight => self
+                .measured_cap_height()
+                .unwrap_or_else(|| font_height(self)),
+        };
+
+        ScaledFont {
+            font: self,
+            scale: size / height,
+            baseline: self.baseline() as f64,
+        }
+    }
+}
+
+impl<'a> ScaledFont<'a> {
+    pub fn font(&self) -> &'a HersheyFont {
+        self.font
+    }
+
+    pub fn ascent(&self) -> f64 {
+        (self.baseline - self.font.top as f64).max(0.0) * self.scale
+    }
+
+    pub fn descent(&self) -> f64 {
+        (self.font.bottom as f64 - self.baseline).max(0.0) * self.scale
+    }
+
+    pub fn line_height(&self) -> f64 {
+        font_height(self.font) * self.scale
+    }
+
+    pub fn scale_glyph(&self, glyph: &HersheyGlyph) -> ScaledGlyph {
+        let point = |x: i32, y: i32| PointF {
+            x: (x - glyph.left) as f64 * self.scale,
+            y: (y as f64 - self.baseline) * self.scale,
+        };
+
+        ScaledGlyph {
+            advance: (glyph.right - glyph.left) as f64 * self.scale,
+            top: (glyph.top as f64 - self.baseline) * self.scale,
+            bottom: (glyph.bottom as f64 - self.baseline) * self.scale,
+            paths: glyph
+                .paths
+                .iter()
+                .map(|path| path.iter().map(|edge| point(edge.x, edge.y)).collect())
+                .collect(),
+        }
+    }
+
+    pub fn get_glyph(&self, glyph: char) -> Option<ScaledGlyph> {
+        self.font
+            .get_glyph_checked(glyph)
+            .map(|glyph| self.scale_glyph(glyph))
+    }
+
+    pub fn measure(&self, text: &str) -> f64 {
+        self.font.measure_text(text) as f64 * self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaled_works() {
+        let mut data = "  720  1JZ\n".repeat(40);
+
+        data.push_str("  720  6G]RBRK RRRRW");
+
+        let font = HersheyFont::new(&data).unwrap();
+        let scaled = font.scaled(42.0, SizeReference::CapHeight);
+        let glyph = scaled.get_glyph('H').unwrap();
+
+        assert_eq!(scaled.scale, 2.0);
+        assert_eq!(glyph.advance, 44.0);
+        assert_eq!((glyph.top, glyph.bottom), (-42.0, 0.0));
+        assert_eq!(glyph.paths[0][0], PointF { x: 22.0, y: -42.0 });
+        assert_eq!(scaled.measure("HH"), 88.0);
+        assert_eq!(font.scaled(21.0, SizeReference::FontHeight).scale, 1.0);
+    }
+}
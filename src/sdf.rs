@@ -0,0 +1,107 @@
+use crate::bounds::edges_bounds;
+use crate::raster::{segment_distance, Bitmap};
+use crate::{Edge, HersheyGlyph};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SdfOptions {
+    pub scale: f64,
+    pub stroke_radius: f64,
+    pub spread: f64,
+}
+
+impl Default for SdfOptions {
+    fn default() -> SdfOptions {
+        SdfOptions {
+            scale: 1.0,
+            stroke_radius: 1.0,
+            spread: 4.0,
+        }
+    }
+}
+
+pub fn generate_sdf(paths: &[Vec<Edge>], options: &SdfOptions) -> Bitmap {
+    let radius = options.stroke_radius * options.scale;
+    let padding = (radius + options.spread).ceil();
+
+    let Some(bounds) = edges_bounds(paths.iter().flatten()) else {
+        return Bitmap {
+            width: 0,
+            height: 0,
+            origin: (0.0, 0.0),
+            pixels: Vec::new(),
+        };
+    };
+
+    let origin = (
+        padding - bounds.left * options.scale,
+        padding - bounds.top * options.scale,
+    );
+    let width = (bounds.width() * options.scale + 2.0 * padding).ceil() as usize;
+    let height = (bounds.height() * options.scale + 2.0 * padding).ceil() as usize;
+
+    let segments = paths
+        .iter()
+        .flat_map(|path| {
+            let points = path
+                .iter()
+                .map(|edge| {
+                    (
+                        origin.0 + edge.x as f64 * options.scale,
+                        origin.1 + edge.y as f64 * options.scale,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            match points.len() {
+                1 => vec![(points[0], points[0])],
+                _ => points.windows(2).map(|pair| (pair[0], pair[1])).collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut pixels = Vec::with_capacity(width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            let p = (x as f64 + 0.5, y as f64 + 0.5);
+            let distance = segments
+                .iter()
+                .map(|(a, b)| segment_distance(p, *a, *b))
+                .fold(f64::INFINITY, f64::min);
+            let value = 0.5 + (radius - distance) / (2.0 * options.spread);
+
+            pixels.push((value.clamp(0.0, 1.0) * 255.0).round() as u8);
+        }
+    }
+
+    Bitmap {
+        width,
+        height,
+        origin,
+        pixels,
+    }
+}
+
+impl HersheyGlyph {
+    pub fn sdf(&self, options: &SdfOptions) -> Bitmap {
+        generate_sdf(&self.paths, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_sdf_works() {
+        let paths = vec![vec![Edge { x: 0, y: 0 }, Edge { x: 10, y: 0 }]];
+        let bitmap = generate_sdf(&paths, &SdfOptions::default());
+
+        let center = bitmap.get(10, bitmap.origin.1 as usize).unwrap();
+        let edge = bitmap.get(10, 0).unwrap();
+
+        assert_eq!(bitmap.origin, (5.0, 5.0));
+        assert!(center > 128);
+        assert!(edge < 128);
+    }
+}
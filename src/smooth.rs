@@ -0,0 +1,282 @@
+use crate::bounds::Bounds;
+use crate::{HersheyGlyph, PointF};
+
+const CURVE_SEGMENTS: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SmoothOptions {
+    pub tolerance: f64,
+    pub corner_angle_degrees: f64,
+}
+
+impl Default for SmoothOptions {
+    fn default() -> SmoothOptions {
+        SmoothOptions {
+            tolerance: 0.5,
+            corner_angle_degrees: 60.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicSegment {
+    pub control1: PointF,
+    pub control2: PointF,
+    pub end: PointF,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurvePath {
+    pub start: PointF,
+    pub segments: Vec<CubicSegment>,
+}
+
+impl CurvePath {
+    pub fn bounds(&self) -> Bounds {
+        self.segments
+            .iter()
+            .flat_map(|segment| [segment.control1, segment.control2, segment.end])
+            .fold(Bounds::from(self.start), |bounds, point| {
+                bounds.union(&Bounds::from(point))
+            })
+    }
+
+    pub fn flatten(&self, segments_per_curve: usize) -> Vec<PointF> {
+        let steps = segments_per_curve.max(1);
+        let mut points = vec![self.start];
+        let mut from = self.start;
+
+        for segment in &self.segments {
+            for step in 1..=steps {
+                let t = step as f64 / steps as f64;
+                let u = 1.0 - t;
+                let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+
+                points.push(PointF {
+                    x: a * from.x
+                        + b * segment.control1.x
+                        + c * segment.control2.x
+                        + d * segment.end.x,
+                    y: a * from.y
+                        + b * segment.control1.y
+                        + c * segment.control2.y
+                        + d * segment.end.y,
+                });
+            }
+
+            from = segment.end;
+        }
+
+        points
+    }
+}
+
+fn turn_angle(previous: PointF, point: PointF, next: PointF) -> f64 {
+    let (ax, ay) = (point.x - previous.x, point.y - previous.y);
+    let (bx, by) = (next.x - point.x, next.y - point.y);
+
+    (ax * by - ay * bx)
+        .atan2(ax * bx + ay * by)
+        .abs()
+        .to_degrees()
+}
+
+fn distance_to_segment(point: PointF, start: PointF, end: PointF) -> f64 {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let length_squared = dx * dx + dy * dy;
+
+    if length_squared == 0.0 {
+        return (point.x - start.x).hypot(point.y - start.y);
+    }
+
+    let t =
+        (((point.x - start.x) * dx + (point.y - start.y) * dy) / length_squared).clamp(0.0, 1.0);
+
+    (point.x - (start.x + t * dx)).hypot(point.y - (start.y + t * dy))
+}
+
+fn simplify(points: &[PointF], tolerance: f64, output: &mut Vec<PointF>) {
+    let last = points.len() - 1;
+    let farthest = (1..last)
+        .map(|i| (i, distance_to_segment(points[i], points[0], points[last])))
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+
+    match farthest {
+        Some((index, distance)) if distance > tolerance => {
+            simplify(&points[..=index], tolerance, output);
+            simplify(&points[index..], tolerance, output);
+        }
+        _ => output.push(points[last]),
+    }
+}
+
+fn catmull_rom(points: &[PointF], closed: bool, segments: &mut Vec<CubicSegment>) {
+    let last = points.len() - 1;
+    let neighbor = |index: isize| -> PointF {
+        if closed {
+            points[index.rem_euclid(last as isize) as usize]
+        } else {
+            points[index.clamp(0, last as isize) as usize]
+        }
+    };
+
+    for i in 0..last {
+        let i = i as isize;
+        let (p0, p1, p2, p3) = (
+            neighbor(i - 1),
+            neighbor(i),
+            neighbor(i + 1),
+            neighbor(i + 2),
+        );
+
+        segments.push(CubicSegment {
+            control1: PointF {
+                x: p1.x + (p2.x - p0.x) / 6.0,
+                y: p1.y + (p2.y - p0.y) / 6.0,
+            },
+            control2: PointF {
+                x: p2.x - (p3.x - p1.x) / 6.0,
+                y: p2.y - (p3.y - p1.y) / 6.0,
+            },
+            end: p2,
+        });
+    }
+}
+
+pub fn smooth_path(path: &[PointF], options: &SmoothOptions) -> Option<CurvePath> {
+    let start = *path.first()?;
+    let last = path.len() - 1;
+    let closed = last > 2 && path[0] == path[last];
+
+    let mut corners = (1..last)
+        .filter(|&i| turn_angle(path[i - 1], path[i], path[i + 1]) > options.corner_angle_degrees)
+        .collect::<Vec<_>>();
+    let wraps = closed
+        && corners.is_empty()
+        && turn_angle(path[last - 1], path[0], path[1]) <= options.corner_angle_degrees;
+
+    corners.insert(0, 0);
+    corners.push(last);
+
+    let mut segments = Vec::new();
+
+    for run in corners.windows(2) {
+        let run = &path[run[0]..=run[1]];
+        let mut points = vec![run[0]];
+
+        if run.len() > 1 {
+            simplify(run, options.tolerance, &mut points);
+        }
+
+        catmull_rom(&points, wraps, &mut segments);
+    }
+
+    Some(CurvePath { start, segments })
+}
+
+impl HersheyGlyph {
+    pub fn to_beziers(&self, options: &SmoothOptions) -> Vec<CurvePath> {
+        self.paths()
+            .filter_map(|path| {
+                let points = path.iter().copied().map(PointF::from).collect::<Vec<_>>();
+
+                smooth_path(&points, options)
+            })
+            .collect()
+    }
+
+    pub fn smooth(&self, options: &SmoothOptions) -> Vec<Vec<PointF>> {
+        self.to_beziers(options)
+            .iter()
+            .map(|curve| curve.flatten(CURVE_SEGMENTS))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Edge;
+
+    fn point(x: f64, y: f64) -> PointF {
+        PointF { x, y }
+    }
+
+    #[test]
+    fn smooth_path_passes_through_points_and_keeps_corners() {
+        let path = [point(0.0, 0.0), point(5.0, 10.0), point(10.0, 0.0)];
+        let curve = smooth_path(&path, &SmoothOptions::default()).unwrap();
+
+        assert_eq!(curve.start, path[0]);
+        assert_eq!(curve.segments.len(), 2);
+        assert_eq!(curve.segments[0].end, path[1]);
+        assert_eq!(
+            curve.segments[0].control2,
+            point(5.0 - 5.0 / 6.0, 10.0 - 10.0 / 6.0)
+        );
+        assert_eq!(
+            curve.segments[1].control1,
+            point(5.0 + 5.0 / 6.0, 10.0 - 10.0 / 6.0)
+        );
+        assert_eq!(curve.segments[1].end, path[2]);
+    }
+
+    #[test]
+    fn smooth_path_rounds_gentle_bends() {
+        let path = [
+            point(0.0, 0.0),
+            point(4.0, 1.0),
+            point(8.0, 3.0),
+            point(12.0, 6.0),
+        ];
+        let curve = smooth_path(
+            &path,
+            &SmoothOptions {
+                tolerance: 0.0,
+                ..SmoothOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(curve.segments.len(), 3);
+        assert_eq!(
+            curve.segments[1].control1,
+            point(4.0 + 8.0 / 6.0, 1.0 + 0.5)
+        );
+        assert_eq!(curve.flatten(4).len(), 13);
+    }
+
+    #[test]
+    fn smooth_path_drops_points_within_tolerance() {
+        let path = [point(0.0, 0.0), point(5.0, 0.2), point(10.0, 0.0)];
+        let curve = smooth_path(
+            &path,
+            &SmoothOptions {
+                corner_angle_degrees: 180.0,
+                ..SmoothOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(curve.segments.len(), 1);
+        assert!(smooth_path(&[], &SmoothOptions::default()).is_none());
+    }
+
+    #[test]
+    fn glyph_smooth_works() {
+        let glyph = HersheyGlyph::new(
+            -5,
+            5,
+            vec![vec![
+                Edge { x: 0, y: 0 },
+                Edge { x: 4, y: 2 },
+                Edge { x: 8, y: 2 },
+            ]],
+        );
+        let smoothed = glyph.smooth(&SmoothOptions::default());
+
+        assert_eq!(smoothed.len(), 1);
+        assert_eq!(smoothed[0].len(), 2 * CURVE_SEGMENTS + 1);
+        assert_eq!(smoothed[0].last(), Some(&point(8.0, 2.0)));
+    }
+}
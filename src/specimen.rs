@@ -0,0 +1,205 @@
+use crate::text::{font_height, place_text};
+use crate::{HersheyFont, PointF};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpecimenOptions {
+    pub columns: usize,
+    pub cell_size: f64,
+    pub sample_text: String,
+    pub sample_sizes: Vec<f64>,
+    pub labels: bool,
+}
+
+impl Default for SpecimenOptions {
+    fn default() -> SpecimenOptions {
+        SpecimenOptions {
+            columns: 16,
+            cell_size: 40.0,
+            sample_text: "The quick brown fox jumps over the lazy dog".to_string(),
+            sample_sizes: vec![12.0, 18.0, 24.0],
+            labels: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Specimen {
+    pub width: f64,
+    pub height: f64,
+    pub paths: Vec<Vec<PointF>>,
+}
+
+impl Specimen {
+    pub fn to_svg(&self, stroke_width: f64) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+            self.width, self.height, self.width, self.height
+        );
+
+        svg.push_str(&format!(
+            "<g fill=\"none\" stroke=\"black\" stroke-width=\"{}\" stroke-linecap=\"round\" stroke-linejoin=\"round\">\n",
+            stroke_width
+        ));
+
+        for path in self.paths.iter().filter(|path| !path.is_empty()) {
+            let points = path
+                .iter()
+                .map(|point| format!("{:.2},{:.2}", point.x, point.y))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            svg.push_str(&format!("<polyline points=\"{}\"/>\n", points));
+        }
+
+        svg.push_str("</g>\n</svg>\n");
+        svg
+    }
+}
+
+fn glyph_label(font: &HersheyFont, index: usize) -> String {
+    match font.chars_for_glyph(index).first() {
+        Some(c) => (*c as u32).to_string(),
+        None => font.glyphs[index].number.to_string(),
+    }
+}
+
+pub fn specimen(font: &HersheyFont, options: &SpecimenOptions) -> Specimen {
+    let font_height = font_height(font);
+    let glyph_scale = options.cell_size * 0.6 / font_height;
+    let label_scale = options.cell_size * 0.15 / font_height;
+    let columns = options.columns.max(1);
+    let mut paths = Vec::new();
+
+    for (i, glyph) in font.glyphs.iter().enumerate() {
+        let (column, row) = (i % columns, i / columns);
+        let cell_x = column as f64 * options.cell_size;
+        let cell_y = row as f64 * options.cell_size;
+
+        paths.push(vec![
+            PointF {
+                x: cell_x,
+                y: cell_y,
+            },
+            PointF {
+                x: cell_x + options.cell_size,
+                y: cell_y,
+            },
+            PointF {
+                x: cell_x + options.cell_size,
+                y: cell_y + options.cell_size,
+            },
+            PointF {
+                x: cell_x,
+                y: cell_y + options.cell_size,
+            },
+            PointF {
+                x: cell_x,
+                y: cell_y,
+            },
+        ]);
+
+        let center_x = cell_x + options.cell_size / 2.0;
+        let center_y = cell_y + options.cell_size / 2.0;
+        let advance = (glyph.right - glyph.left) as f64 * glyph_scale;
+
+        paths.extend(glyph.paths.iter().map(|path| {
+            path.iter()
+                .map(|edge| PointF {
+                    x: center_x - advance / 2.0 + (edge.x - glyph.left) as f64 * glyph_scale,
+                    y: center_y + edge.y as f64 * glyph_scale,
+                })
+                .collect()
+        }));
+
+        if options.labels {
+            let origin = PointF {
+                x: cell_x + options.cell_size * 0.05,
+                y: cell_y + options.cell_size * 0.9,
+            };
+
+            paths.extend(place_text(font, &glyph_label(font, i), origin, label_scale).0);
+        }
+    }
+
+    let rows = font.glyphs.len().div_ceil(columns);
+    let mut height = rows as f64 * options.cell_size;
+    let mut width = columns.min(font.glyphs.len()) as f64 * options.cell_size;
+
+    for size in &options.sample_sizes {
+        let scale = size / font_height;
+        let origin = PointF {
+            x: 0.0,
+            y: height + size * 0.75,
+        };
+        let (sample, sample_width) = place_text(font, &options.sample_text, origin, scale);
+
+        paths.extend(sample);
+        height += size * 1.5;
+        width = width.max(sample_width);
+    }
+
+    Specimen {
+        width,
+        height,
+        paths,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapping::CharMapping;
+    use std::collections::HashMap;
+
+    #[test]
+    fn specimen_works() {
+        let font = HersheyFont::new(&vec!["  720  3G][BIb"; 20].join("\n")).unwrap();
+        let specimen = specimen(
+            &font,
+            &SpecimenOptions {
+                columns: 8,
+                sample_text: "!!".to_string(),
+                sample_sizes: vec![32.0],
+                labels: false,
+                ..SpecimenOptions::default()
+            },
+        );
+
+        assert_eq!(specimen.width, 320.0);
+        assert_eq!(specimen.height, 168.0);
+        assert_eq!(specimen.paths.len(), 20 * 2 + 2);
+    }
+
+    #[test]
+    fn glyph_label_follows_mapping() {
+        let font = HersheyFont::new("  720  1JZ\n  721  1JZ").unwrap();
+
+        assert_eq!(glyph_label(&font, 1), "33");
+        assert_eq!(
+            glyph_label(
+                &font
+                    .clone()
+                    .with_mapping(CharMapping::Custom(HashMap::from([('Ω', 0)]))),
+                0
+            ),
+            "937"
+        );
+        assert_eq!(
+            glyph_label(&font.with_mapping(CharMapping::Custom(HashMap::new())), 1),
+            "721"
+        );
+    }
+
+    #[test]
+    fn to_svg_works() {
+        let specimen = Specimen {
+            width: 10.0,
+            height: 10.0,
+            paths: vec![vec![PointF { x: 0.0, y: 0.0 }, PointF { x: 1.0, y: 1.0 }]],
+        };
+        let svg = specimen.to_svg(0.5);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<polyline points=\"0.00,0.00 1.00,1.00\"/>"));
+    }
+}
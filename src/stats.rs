@@ -0,0 +1,107 @@
+use crate::optimize::travel_distance;
+use crate::{Edge, HersheyFont, HersheyGlyph};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GlyphStats {
+    pub path_count: usize,
+    pub vertex_count: usize,
+    pub stroke_length: f64,
+    pub pen_up_travel: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FontStats {
+    pub glyph_count: usize,
+    pub path_count: usize,
+    pub vertex_count: usize,
+    pub stroke_length: f64,
+    pub pen_up_travel: f64,
+    pub glyphs: Vec<GlyphStats>,
+    pub path_count_histogram: BTreeMap<usize, usize>,
+    pub vertex_count_histogram: BTreeMap<usize, usize>,
+}
+
+pub(crate) fn path_length(path: &[Edge]) -> f64 {
+    path.windows(2)
+        .map(|pair| pair[0].distance_to(&pair[1]))
+        .sum()
+}
+
+impl HersheyGlyph {
+    pub fn stats(&self) -> GlyphStats {
+        GlyphStats {
+            path_count: self.paths.len(),
+            vertex_count: self.paths.iter().map(Vec::len).sum(),
+            stroke_length: self.paths.iter().map(|path| path_length(path)).sum(),
+            pen_up_travel: travel_distance(&self.paths, None),
+        }
+    }
+}
+
+impl HersheyFont {
+    pub fn stats(&self) -> FontStats {
+        let glyphs = self
+            .glyphs
+            .iter()
+            .map(HersheyGlyph::stats)
+            .collect::<Vec<_>>();
+
+        let mut stats = FontStats {
+            glyph_count: glyphs.len(),
+            ..FontStats::default()
+        };
+
+        for glyph in &glyphs {
+            stats.path_count += glyph.path_count;
+            stats.vertex_count += glyph.vertex_count;
+            stats.stroke_length += glyph.stroke_length;
+            stats.pen_up_travel += glyph.pen_up_travel;
+
+            *stats
+                .path_count_histogram
+                .entry(glyph.path_count)
+                .or_default() += 1;
+            *stats
+                .vertex_count_histogram
+                .entry(glyph.vertex_count)
+                .or_default() += 1;
+        }
+
+        stats.glyphs = glyphs;
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_stats_works() {
+        let font = HersheyFont::new("  720  6G]RBRK RRRRW").unwrap();
+        let stats = font.get_glyph(' ').unwrap().stats();
+
+        assert_eq!(
+            stats,
+            GlyphStats {
+                path_count: 2,
+                vertex_count: 4,
+                stroke_length: 14.0,
+                pen_up_travel: 7.0,
+            }
+        );
+    }
+
+    #[test]
+    fn font_stats_works() {
+        let font = HersheyFont::new("  720  3G][BIb\n  720  1JZ").unwrap();
+        let stats = font.stats();
+
+        assert_eq!(stats.glyph_count, 2);
+        assert_eq!(stats.path_count, 1);
+        assert_eq!(stats.vertex_count, 2);
+        assert_eq!(stats.path_count_histogram.get(&0), Some(&1));
+        assert_eq!(stats.vertex_count_histogram.get(&2), Some(&1));
+    }
+}
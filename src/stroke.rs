@@ -0,0 +1,61 @@
+use crate::classify::StrokeClass;
+use crate::raster::RasterOptions;
+use crate::text::font_height;
+use crate::HersheyFont;
+
+const SINGLE_STROKE_RATIO: f64 = 0.08;
+const MULTI_STROKE_RATIO: f64 = 0.04;
+
+impl HersheyFont {
+    pub fn recommended_stroke_width(&self) -> f64 {
+        self.meta()
+            .and_then(|meta| meta.stroke_width)
+            .unwrap_or_else(|| match self.class().stroke {
+                StrokeClass::SingleStroke => SINGLE_STROKE_RATIO,
+                StrokeClass::MultiStroke => MULTI_STROKE_RATIO,
+            })
+    }
+
+    pub fn suggest_stroke_width(&self, scale: f64) -> f64 {
+        let cap_height = self
+            .measured_cap_height()
+            .unwrap_or_else(|| font_height(self));
+
+        self.recommended_stroke_width() * cap_height * scale
+    }
+}
+
+impl RasterOptions {
+    pub fn for_font(font: &HersheyFont, scale: f64) -> RasterOptions {
+        RasterOptions {
+            scale,
+            stroke_width: font.suggest_stroke_width(1.0),
+            ..RasterOptions::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggest_stroke_width_uses_cap_height() {
+        let mut data = "  720  1JZ\n".repeat(40);
+
+        data.push_str("  720  6G]RBRK RRRRW");
+
+        let font = HersheyFont::new(&data).unwrap();
+
+        assert_eq!(font.recommended_stroke_width(), SINGLE_STROKE_RATIO);
+        assert!((font.suggest_stroke_width(0.5) - 0.08 * 21.0 * 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn recommended_stroke_width_prefers_metadata() {
+        let font = HersheyFont::new("stroke-width: 0.12\n  720  3JZRHR\\").unwrap();
+
+        assert_eq!(font.recommended_stroke_width(), 0.12);
+        assert_eq!(font.suggest_stroke_width(1.0), 0.12 * 20.0);
+    }
+}
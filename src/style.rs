@@ -0,0 +1,136 @@
+use crate::layout::LayoutOptions;
+use crate::text::layout_to_paths;
+use crate::{HersheyFont, PointF};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub pen: u32,
+    pub color: Option<String>,
+    pub tool: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledSpan {
+    pub text: String,
+    pub style: Style,
+}
+
+impl StyledSpan {
+    pub fn new(text: &str, style: Style) -> StyledSpan {
+        StyledSpan {
+            text: text.to_string(),
+            style,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledPaths {
+    pub style: Style,
+    pub paths: Vec<Vec<PointF>>,
+}
+
+pub fn styled_text_to_paths(
+    font: &HersheyFont,
+    spans: &[StyledSpan],
+    size: f64,
+) -> Vec<StyledPaths> {
+    styled_text_to_paths_with_options(font, spans, size, &LayoutOptions::default())
+}
+
+pub fn styled_text_to_paths_with_options(
+    font: &HersheyFont,
+    spans: &[StyledSpan],
+    size: f64,
+    options: &LayoutOptions,
+) -> Vec<StyledPaths> {
+    let text = spans
+        .iter()
+        .map(|span| span.text.as_str())
+        .collect::<String>();
+    let ends = spans
+        .iter()
+        .scan(0, |end, span| {
+            *end += span.text.chars().count();
+            Some(*end)
+        })
+        .collect::<Vec<_>>();
+    let layout = font.layout_text_with_options(&text, options);
+    let mut layers: Vec<StyledPaths> = Vec::new();
+
+    for glyph in &layout.glyphs {
+        let style = &spans[ends.partition_point(|end| *end <= glyph.index)].style;
+        let paths = layout_to_paths(font, std::slice::from_ref(glyph), size);
+
+        match layers.iter_mut().find(|layer| layer.style == *style) {
+            Some(layer) => layer.paths.extend(paths),
+            None => layers.push(StyledPaths {
+                style: style.clone(),
+                paths,
+            }),
+        }
+    }
+
+    layers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styled_text_to_paths_groups_by_style() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\").unwrap();
+        let red = Style {
+            pen: 2,
+            color: Some("red".to_string()),
+            tool: None,
+        };
+        let layers = styled_text_to_paths(
+            &font,
+            &[
+                StyledSpan::new("!", Style::default()),
+                StyledSpan::new("!!", red.clone()),
+                StyledSpan::new("\n!", Style::default()),
+            ],
+            20.0,
+        );
+
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0].paths.len(), 2);
+        assert_eq!(layers[1].style, red);
+        assert_eq!(layers[1].paths.len(), 2);
+        assert_eq!(layers[1].paths[0][0], PointF { x: 24.0, y: -10.0 });
+        assert_eq!(layers[0].paths[1][0], PointF { x: 8.0, y: 10.0 });
+    }
+
+    #[test]
+    fn styled_text_to_paths_with_options_uses_layout() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\").unwrap();
+        let red = Style {
+            pen: 2,
+            ..Style::default()
+        };
+        let layers = styled_text_to_paths_with_options(
+            &font,
+            &[
+                StyledSpan::new("!", Style::default()),
+                StyledSpan::new(" !", red.clone()),
+            ],
+            20.0,
+            &LayoutOptions {
+                max_width: Some(20),
+                tracking: 4,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[0].paths[0][0], PointF { x: 8.0, y: -10.0 });
+        assert_eq!(layers[1].style, red);
+        assert_eq!(
+            layers[1].paths,
+            vec![vec![PointF { x: 8.0, y: 10.0 }, PointF { x: 8.0, y: 30.0 }]]
+        );
+    }
+}
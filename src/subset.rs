@@ -0,0 +1,115 @@
+use crate::mapping::CharMapping;
+use crate::{HersheyFont, HersheyGlyph};
+use std::collections::{BTreeMap, HashMap};
+
+pub(crate) fn font_from_mapped_glyphs(
+    metadata: BTreeMap<String, String>,
+    glyphs: BTreeMap<char, HersheyGlyph>,
+) -> HersheyFont {
+    let mapping = if glyphs
+        .keys()
+        .enumerate()
+        .all(|(i, c)| *c as usize == i + 32)
+    {
+        CharMapping::AsciiOffset
+    } else {
+        CharMapping::Custom(
+            glyphs
+                .keys()
+                .enumerate()
+                .map(|(i, c)| (*c, i))
+                .collect::<HashMap<_, _>>(),
+        )
+    };
+
+    HersheyFont::from_parts(metadata, glyphs.into_values().collect()).with_mapping(mapping)
+}
+
+impl HersheyFont {
+    pub(crate) fn mapped_glyphs(&self) -> impl Iterator<Item = (char, &HersheyGlyph)> {
+        self.glyphs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, glyph)| self.chars_for_glyph(i).into_iter().map(move |c| (c, glyph)))
+    }
+
+    pub fn subset(&self, chars: impl IntoIterator<Item = char>) -> HersheyFont {
+        let glyphs = chars
+            .into_iter()
+            .filter_map(|c| Some((c, self.get_glyph_checked(c)?.clone())))
+            .collect();
+
+        HersheyFont {
+            coordinate_system: self.coordinate_system,
+            ..font_from_mapped_glyphs(self.metadata.clone(), glyphs)
+        }
+    }
+
+    pub fn merge(&self, other: &HersheyFont, range_offset: u32) -> HersheyFont {
+        let mut glyphs = self
+            .mapped_glyphs()
+            .map(|(c, glyph)| (c, glyph.clone()))
+            .collect::<BTreeMap<_, _>>();
+
+        for (c, glyph) in other.mapped_glyphs() {
+            if let Some(c) = (c as u32)
+                .checked_add(range_offset)
+                .and_then(char::from_u32)
+            {
+                glyphs.entry(c).or_insert_with(|| glyph.clone());
+            }
+        }
+
+        HersheyFont {
+            coordinate_system: self.coordinate_system,
+            ..font_from_mapped_glyphs(self.metadata.clone(), glyphs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+
+    #[test]
+    fn subset_works() {
+        let font = HersheyFont::new("  699  1JZ\n  714  3MWRHR\\\n  715  3MWRFRT").unwrap();
+        let subset = font.subset("\"!?".chars());
+
+        assert_eq!(subset.len(), 2);
+        assert_eq!(subset.get_glyph('!').unwrap().number, 714);
+        assert_eq!(subset.get_glyph('"').unwrap().number, 715);
+        assert!(subset.get_glyph(' ').is_err());
+        assert_eq!((subset.top, subset.bottom), (-12, 10));
+    }
+
+    #[test]
+    fn subset_and_merge_keep_coordinate_system() {
+        let mut font = HersheyFont::new("  699  1JZ\n  714  3MWRHR\\").unwrap();
+        font.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            font.subset("!".chars()).coordinate_system,
+            CoordinateSystem::BaselineLeft
+        );
+        assert_eq!(
+            font.merge(&HersheyFont::new("").unwrap(), 0)
+                .coordinate_system,
+            CoordinateSystem::BaselineLeft
+        );
+    }
+
+    #[test]
+    fn merge_works() {
+        let latin = HersheyFont::new("  699  1JZ\n  714  3MWRHR\\").unwrap();
+        let symbols = HersheyFont::new("  840  1JZ\n  841  3MWRFRT").unwrap();
+        let merged = latin.merge(&symbols, 0x100);
+
+        assert_eq!(merged.len(), 4);
+        assert_eq!(merged.get_glyph('!').unwrap().number, 714);
+        assert_eq!(merged.get_glyph('\u{121}').unwrap().number, 841);
+        assert_eq!(merged.top, -12);
+        assert_eq!(latin.merge(&symbols, 0).get_glyph('!').unwrap().number, 714);
+    }
+}
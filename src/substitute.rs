@@ -0,0 +1,108 @@
+use crate::HersheyFont;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TargetMetrics {
+    pub cap_height: f64,
+    pub average_advance: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MetricAdjustment {
+    pub scale: f64,
+    pub tracking: i32,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum HersheyFontMatchMetricsError {
+    #[error("{0}")]
+    MissingReferenceGlyph(String),
+}
+
+impl HersheyFont {
+    pub fn match_metrics(
+        &self,
+        target: &TargetMetrics,
+    ) -> Result<MetricAdjustment, HersheyFontMatchMetricsError> {
+        let cap_height = self.measured_cap_height().ok_or(
+            HersheyFontMatchMetricsError::MissingReferenceGlyph(
+                "Glyph H is required to measure cap height".to_string(),
+            ),
+        )?;
+
+        let average_advance = self
+            .glyphs
+            .iter()
+            .map(|glyph| (glyph.right - glyph.left) as f64)
+            .sum::<f64>()
+            / self.glyphs.len() as f64;
+
+        let scale = target.cap_height / cap_height;
+
+        Ok(MetricAdjustment {
+            scale,
+            tracking: (target.average_advance / scale - average_advance).round() as i32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::HersheyFontBuilder;
+    use crate::layout::LayoutOptions;
+    use crate::{Edge, HersheyGlyph};
+
+    #[test]
+    fn match_metrics_works() {
+        let font = HersheyFontBuilder::new()
+            .glyph(
+                'H',
+                HersheyGlyph::new(
+                    -8,
+                    8,
+                    vec![vec![Edge { x: 0, y: -10 }, Edge { x: 0, y: 10 }]],
+                ),
+            )
+            .build()
+            .unwrap();
+
+        let adjustment = font
+            .match_metrics(&TargetMetrics {
+                cap_height: 10.0,
+                average_advance: 12.0,
+            })
+            .unwrap();
+
+        assert_eq!(
+            adjustment,
+            MetricAdjustment {
+                scale: 0.5,
+                tracking: 8,
+            }
+        );
+
+        let layout = font.layout_text_with_options(
+            "HH",
+            &LayoutOptions {
+                tracking: adjustment.tracking,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(layout.width as f64 * adjustment.scale, 20.0);
+    }
+
+    #[test]
+    fn match_metrics_returns_error_without_reference_glyph() {
+        let font = HersheyFont::new("  720  3G][BIb").unwrap();
+        let result = font.match_metrics(&TargetMetrics {
+            cap_height: 10.0,
+            average_advance: 12.0,
+        });
+
+        assert!(matches!(
+            result,
+            Err(HersheyFontMatchMetricsError::MissingReferenceGlyph(_))
+        ));
+    }
+}
@@ -0,0 +1,322 @@
+use crate::gcode::format_number;
+use crate::html::escape_html;
+use crate::layout::TextLayout;
+use crate::style::StyledPaths;
+use crate::units::Units;
+use crate::{Edge, HersheyFont, HersheyGlyph, PointF};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+    pub scale: f64,
+    pub stroke_width: f64,
+    pub flip_y: bool,
+    pub padding: f64,
+    pub units: Option<Units>,
+    pub comments: Vec<String>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> SvgOptions {
+        SvgOptions {
+            scale: 1.0,
+            stroke_width: 1.0,
+            flip_y: false,
+            padding: 0.0,
+            units: None,
+            comments: Vec::new(),
+        }
+    }
+}
+
+fn transform(point: PointF, options: &SvgOptions) -> PointF {
+    PointF {
+        x: point.x * options.scale,
+        y: point.y * options.scale * if options.flip_y { -1.0 } else { 1.0 },
+    }
+}
+
+fn xml_comment(text: &str) -> String {
+    let mut text = text.to_string();
+
+    while text.contains("--") {
+        text = text.replace("--", "- -");
+    }
+
+    if text.ends_with('-') {
+        text.push(' ');
+    }
+
+    text
+}
+
+fn to_points(paths: &[Vec<Edge>]) -> Vec<Vec<PointF>> {
+    paths
+        .iter()
+        .map(|path| path.iter().copied().map(PointF::from).collect())
+        .collect()
+}
+
+pub fn path_data_points(paths: &[Vec<PointF>], options: &SvgOptions) -> String {
+    paths
+        .iter()
+        .filter(|path| !path.is_empty())
+        .map(|path| {
+            path.iter()
+                .enumerate()
+                .map(|(i, point)| {
+                    let point = transform(*point, options);
+
+                    format!(
+                        "{}{} {}",
+                        if i == 0 { "M" } else { "L" },
+                        format_number(point.x),
+                        format_number(point.y)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn path_data(paths: &[Vec<Edge>], options: &SvgOptions) -> String {
+    path_data_points(&to_points(paths), options)
+}
+
+type Layer<'a> = (Option<String>, String, &'a [Vec<PointF>]);
+
+fn document(layers: &[Layer], options: &SvgOptions) -> String {
+    let (left, top, right, bottom) = layers
+        .iter()
+        .flat_map(|(_, _, paths)| paths.iter().flatten())
+        .map(|point| transform(*point, options))
+        .fold(None, |accum: Option<(f64, f64, f64, f64)>, point| {
+            Some(match accum {
+                Some((left, top, right, bottom)) => (
+                    left.min(point.x),
+                    top.min(point.y),
+                    right.max(point.x),
+                    bottom.max(point.y),
+                ),
+                None => (point.x, point.y, point.x, point.y),
+            })
+        })
+        .unwrap_or_default();
+    let margin = options.padding + options.stroke_width / 2.0;
+    let (x, y) = (left - margin, top - margin);
+    let (width, height) = (right - left + 2.0 * margin, bottom - top + 2.0 * margin);
+    let suffix = options.units.map_or("", |units| units.suffix());
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}{}\" height=\"{}{}\" viewBox=\"{} {} {} {}\">\n",
+        format_number(width),
+        suffix,
+        format_number(height),
+        suffix,
+        format_number(x),
+        format_number(y),
+        format_number(width),
+        format_number(height)
+    );
+
+    for comment in &options.comments {
+        svg.push_str(&format!("<!-- {} -->\n", xml_comment(comment)));
+    }
+
+    for (id, color, paths) in layers {
+        let id = id
+            .as_ref()
+            .map_or(String::new(), |id| format!(" id=\"{}\"", escape_html(id)));
+        let data = path_data_points(paths, options);
+
+        svg.push_str(&format!(
+            "<g{} fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"round\" stroke-linejoin=\"round\">\n",
+            id,
+            escape_html(color),
+            format_number(options.stroke_width)
+        ));
+
+        if !data.is_empty() {
+            svg.push_str(&format!("<path d=\"{}\"/>\n", data));
+        }
+
+        svg.push_str("</g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+pub fn write_svg_points(paths: &[Vec<PointF>], options: &SvgOptions) -> String {
+    document(&[(None, "black".to_string(), paths)], options)
+}
+
+pub fn write_svg(paths: &[Vec<Edge>], options: &SvgOptions) -> String {
+    write_svg_points(&to_points(paths), options)
+}
+
+pub fn write_svg_layers(layers: &[StyledPaths], options: &SvgOptions) -> String {
+    let layers = layers
+        .iter()
+        .enumerate()
+        .map(|(i, layer)| {
+            (
+                Some(format!("layer-{}-pen-{}", i, layer.style.pen)),
+                layer
+                    .style
+                    .color
+                    .clone()
+                    .unwrap_or_else(|| "black".to_string()),
+                layer.paths.as_slice(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    document(&layers, options)
+}
+
+impl HersheyGlyph {
+    pub fn to_svg_path_data(&self, options: &SvgOptions) -> String {
+        path_data(&self.paths, options)
+    }
+
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        write_svg(&self.paths, options)
+    }
+}
+
+impl TextLayout {
+    pub fn to_svg_path_data(&self, options: &SvgOptions) -> String {
+        path_data(&self.paths(), options)
+    }
+
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        write_svg(&self.paths(), options)
+    }
+}
+
+impl HersheyFont {
+    pub fn text_to_svg(&self, text: &str, options: &SvgOptions) -> String {
+        let mut options = options.clone();
+
+        if let Some(meta) = self.meta() {
+            options.comments.extend(meta.lines());
+        }
+
+        options.flip_y = options.flip_y != self.y_up();
+        self.layout_text(text).to_svg(&options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinates::CoordinateSystem;
+    use crate::style::Style;
+
+    #[test]
+    fn path_data_works() {
+        let paths = vec![
+            vec![Edge { x: 0, y: -10 }, Edge { x: 0, y: 10 }],
+            vec![Edge { x: 1, y: 1 }, Edge { x: 3, y: 1 }],
+        ];
+        let options = SvgOptions {
+            scale: 0.5,
+            flip_y: true,
+            ..SvgOptions::default()
+        };
+
+        assert_eq!(
+            path_data(&paths, &options),
+            "M0 5 L0 -5 M0.5 -0.5 L1.5 -0.5"
+        );
+    }
+
+    #[test]
+    fn to_svg_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let svg = font.layout_text("!!").to_svg(&SvgOptions {
+            stroke_width: 2.0,
+            units: Some(Units::Millimeters),
+            comments: vec!["Name: test".to_string()],
+            ..SvgOptions::default()
+        });
+
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12mm\" height=\"22mm\" viewBox=\"4 -11 12 22\">\n<!-- Name: test -->\n"
+        ));
+        assert!(svg.contains("<path d=\"M5 -10 L5 10 M15 -10 L15 10\"/>"));
+    }
+
+    #[test]
+    fn write_svg_escapes_comments() {
+        let svg = write_svg(
+            &[],
+            &SvgOptions {
+                comments: vec!["a---b -".to_string()],
+                ..SvgOptions::default()
+            },
+        );
+
+        assert!(svg.contains("<!-- a- - -b -  -->\n"));
+        assert_eq!(xml_comment("x--"), "x- - ");
+    }
+
+    #[test]
+    fn text_to_svg_includes_metadata() {
+        let font = HersheyFont::new("name: futural\n  720  1JZ\n  720  3MWRHR\\").unwrap();
+        let svg = font.text_to_svg("!!", &SvgOptions::default());
+
+        assert!(svg.contains("<!-- Name: futural -->\n"));
+        assert!(svg.contains("<path d=\"M5 -10 L5 10 M15 -10 L15 10\"/>"));
+    }
+
+    #[test]
+    fn write_svg_layers_works() {
+        let layers = [StyledPaths {
+            style: Style {
+                pen: 2,
+                color: Some("red".to_string()),
+                tool: None,
+            },
+            paths: vec![vec![PointF { x: 0.0, y: 0.0 }, PointF { x: 1.0, y: 0.0 }]],
+        }];
+        let svg = write_svg_layers(&layers, &SvgOptions::default());
+
+        assert!(svg.contains("<g id=\"layer-0-pen-2\" fill=\"none\" stroke=\"red\""));
+        assert!(svg.contains("<path d=\"M0 0 L1 0\"/>"));
+    }
+
+    #[test]
+    fn write_svg_layers_uses_unique_ids_and_escapes_colors() {
+        let layer = |color: &str| StyledPaths {
+            style: Style {
+                pen: 1,
+                color: Some(color.to_string()),
+                tool: None,
+            },
+            paths: Vec::new(),
+        };
+        let svg = write_svg_layers(
+            &[layer("red"), layer("\"><script>")],
+            &SvgOptions::default(),
+        );
+
+        assert!(svg.contains("<g id=\"layer-0-pen-1\" fill=\"none\" stroke=\"red\""));
+        assert!(svg
+            .contains("<g id=\"layer-1-pen-1\" fill=\"none\" stroke=\"&quot;&gt;&lt;script&gt;\""));
+    }
+
+    #[test]
+    fn text_to_svg_follows_coordinate_system() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3MWRHRR").unwrap();
+        let mut y_up = font.clone();
+        y_up.coordinate_system = CoordinateSystem::BaselineLeft;
+
+        assert_eq!(
+            y_up.text_to_svg("!\n!", &SvgOptions::default()),
+            font.text_to_svg("!\n!", &SvgOptions::default())
+        );
+    }
+}
@@ -0,0 +1,794 @@
+use crate::subset::font_from_mapped_glyphs;
+use crate::{Edge, HersheyFont, HersheyGlyph};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgImportOptions {
+    pub scale: f64,
+    pub offset: (f64, f64),
+}
+
+impl Default for SvgImportOptions {
+    fn default() -> SvgImportOptions {
+        SvgImportOptions {
+            scale: 1.0,
+            offset: (0.0, 0.0),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SvgImportError {
+    #[error("{0}")]
+    InvalidNumber(String),
+    #[error("{0}")]
+    InvalidPathData(String),
+    #[error("{0}")]
+    UnsupportedCommand(String),
+    #[error("{0}")]
+    InvalidAttribute(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Tag {
+    pub(crate) name: String,
+    pub(crate) attributes: BTreeMap<String, String>,
+    pub(crate) closing: bool,
+    pub(crate) self_closing: bool,
+}
+
+pub(crate) fn decode_entities(value: &str) -> String {
+    let mut output = String::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16).ok())
+                .unwrap_or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+
+        match decoded {
+            Some(c) => {
+                output.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+pub(crate) fn parse_tags(xml: &str) -> Vec<Tag> {
+    let mut tags = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        if rest.starts_with("!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+
+        let body = &rest[..end];
+        rest = &rest[end + 1..];
+
+        if body.starts_with('?') || body.starts_with('!') {
+            continue;
+        }
+
+        let closing = body.starts_with('/');
+        let self_closing = body.ends_with('/');
+        let body = body.trim_start_matches('/').trim_end_matches('/');
+        let name_end = body.find(|c: char| c.is_whitespace()).unwrap_or(body.len());
+        let mut attributes = BTreeMap::new();
+        let mut attribute_text = &body[name_end..];
+
+        while let Some(equals) = attribute_text.find('=') {
+            let key = attribute_text[..equals].trim().to_string();
+            let value_text = attribute_text[equals + 1..].trim_start();
+            let Some(quote) = value_text
+                .chars()
+                .next()
+                .filter(|c| *c == '"' || *c == '\'')
+            else {
+                break;
+            };
+            let Some(value_end) = value_text[1..].find(quote) else {
+                break;
+            };
+
+            attributes.insert(key, decode_entities(&value_text[1..value_end + 1]));
+            attribute_text = &value_text[value_end + 2..];
+        }
+
+        tags.push(Tag {
+            name: body[..name_end].to_string(),
+            attributes,
+            closing,
+            self_closing,
+        });
+    }
+
+    tags
+}
+
+fn parse_numbers(text: &str) -> Result<Vec<f64>, SvgImportError> {
+    let mut numbers = Vec::new();
+    let mut current = String::new();
+
+    let mut flush = |current: &mut String| -> Result<(), SvgImportError> {
+        if !current.is_empty() {
+            numbers.push(current.parse::<f64>().map_err(|_| {
+                SvgImportError::InvalidNumber(format!("Invalid number {}", current))
+            })?);
+            current.clear();
+        }
+
+        Ok(())
+    };
+
+    for c in text.chars() {
+        match c {
+            '-' | '+' if !current.is_empty() && !current.ends_with(['e', 'E']) => {
+                flush(&mut current)?;
+                current.push(c);
+            }
+            '.' if current.contains('.') && !current.contains(['e', 'E']) => {
+                flush(&mut current)?;
+                current.push(c);
+            }
+            c if c.is_ascii_digit() || "+-.eE".contains(c) => current.push(c),
+            _ => flush(&mut current)?,
+        }
+    }
+
+    flush(&mut current)?;
+
+    Ok(numbers)
+}
+
+const CURVE_SEGMENTS: usize = 16;
+
+fn flatten_cubic(
+    path: &mut Vec<(f64, f64)>,
+    from: (f64, f64),
+    c1: (f64, f64),
+    c2: (f64, f64),
+    to: (f64, f64),
+) {
+    for step in 1..=CURVE_SEGMENTS {
+        let t = step as f64 / CURVE_SEGMENTS as f64;
+        let u = 1.0 - t;
+        let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+
+        path.push((
+            a * from.0 + b * c1.0 + c * c2.0 + d * to.0,
+            a * from.1 + b * c1.1 + c * c2.1 + d * to.1,
+        ));
+    }
+}
+
+fn flatten_quadratic(path: &mut Vec<(f64, f64)>, from: (f64, f64), c: (f64, f64), to: (f64, f64)) {
+    for step in 1..=CURVE_SEGMENTS {
+        let t = step as f64 / CURVE_SEGMENTS as f64;
+        let u = 1.0 - t;
+
+        path.push((
+            u * u * from.0 + 2.0 * u * t * c.0 + t * t * to.0,
+            u * u * from.1 + 2.0 * u * t * c.1 + t * t * to.1,
+        ));
+    }
+}
+
+fn flatten_arc(
+    path: &mut Vec<(f64, f64)>,
+    from: (f64, f64),
+    radii: (f64, f64),
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: (f64, f64),
+) {
+    let (mut rx, mut ry) = (radii.0.abs(), radii.1.abs());
+
+    if rx == 0.0 || ry == 0.0 || from == to {
+        path.push(to);
+        return;
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (dx, dy) = ((from.0 - to.0) / 2.0, (from.1 - to.1) / 2.0);
+    let (x1, y1) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let factor = sign * (numerator / denominator).max(0.0).sqrt();
+    let (cx1, cy1) = (factor * rx * y1 / ry, -factor * ry * x1 / rx);
+    let center = (
+        cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.0,
+        sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.0,
+    );
+
+    let angle = |ux: f64, uy: f64| uy.atan2(ux);
+    let start = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry) - start;
+
+    if sweep && delta < 0.0 {
+        delta += std::f64::consts::TAU;
+    } else if !sweep && delta > 0.0 {
+        delta -= std::f64::consts::TAU;
+    }
+
+    for step in 1..=CURVE_SEGMENTS {
+        let theta = start + delta * step as f64 / CURVE_SEGMENTS as f64;
+        let (x, y) = (rx * theta.cos(), ry * theta.sin());
+
+        path.push((center.0 + cos * x - sin * y, center.1 + sin * x + cos * y));
+    }
+
+    path.pop();
+    path.push(to);
+}
+
+pub(crate) fn parse_path_data(data: &str) -> Result<Vec<Vec<(f64, f64)>>, SvgImportError> {
+    let mut paths = Vec::new();
+    let mut path: Vec<(f64, f64)> = Vec::new();
+    let mut position = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+    let mut last_control: Option<(char, (f64, f64))> = None;
+
+    let mut commands = Vec::new();
+    let mut command_start = None;
+
+    for (i, c) in data.char_indices() {
+        if c.is_ascii_alphabetic() && c != 'e' && c != 'E' {
+            if let Some((command, from)) = command_start {
+                commands.push((command, &data[from..i]));
+            }
+
+            command_start = Some((c, i + 1));
+        }
+    }
+
+    if let Some((command, from)) = command_start {
+        commands.push((command, &data[from..]));
+    }
+
+    for (command, arguments) in commands {
+        let numbers = parse_numbers(arguments)?;
+        let relative = command.is_ascii_lowercase();
+        let base = |position: (f64, f64)| if relative { position } else { (0.0, 0.0) };
+        let upper = command.to_ascii_uppercase();
+        let arity = match upper {
+            'M' | 'L' | 'T' => 2,
+            'S' | 'Q' => 4,
+            'C' => 6,
+            'A' => 7,
+            _ => 1,
+        };
+
+        if arity > 1 && numbers.len() % arity != 0 {
+            return Err(SvgImportError::InvalidPathData(format!(
+                "Wrong number of coordinates for {}",
+                command
+            )));
+        }
+
+        if path.is_empty() && upper != 'M' {
+            path.push(position);
+        }
+
+        match upper {
+            'M' | 'L' => {
+                for (i, pair) in numbers.chunks(2).enumerate() {
+                    let origin = base(position);
+                    position = (origin.0 + pair[0], origin.1 + pair[1]);
+
+                    if i == 0 && upper == 'M' {
+                        if path.len() > 1 {
+                            paths.push(path);
+                        }
+
+                        path = vec![position];
+                        start = position;
+                    } else {
+                        path.push(position);
+                    }
+                }
+            }
+            'H' => {
+                for x in numbers {
+                    position = (base(position).0 + x, position.1);
+                    path.push(position);
+                }
+            }
+            'V' => {
+                for y in numbers {
+                    position = (position.0, base(position).1 + y);
+                    path.push(position);
+                }
+            }
+            'C' | 'S' => {
+                for values in numbers.chunks(arity) {
+                    let origin = base(position);
+                    let point = |i: usize| (origin.0 + values[i], origin.1 + values[i + 1]);
+                    let (c1, c2, to) = if upper == 'C' {
+                        (point(0), point(2), point(4))
+                    } else {
+                        let c1 = match last_control {
+                            Some(('C', control)) => {
+                                (2.0 * position.0 - control.0, 2.0 * position.1 - control.1)
+                            }
+                            _ => position,
+                        };
+
+                        (c1, point(0), point(2))
+                    };
+
+                    flatten_cubic(&mut path, position, c1, c2, to);
+                    last_control = Some(('C', c2));
+                    position = to;
+                }
+
+                continue;
+            }
+            'Q' | 'T' => {
+                for values in numbers.chunks(arity) {
+                    let origin = base(position);
+                    let point = |i: usize| (origin.0 + values[i], origin.1 + values[i + 1]);
+                    let (control, to) = if upper == 'Q' {
+                        (point(0), point(2))
+                    } else {
+                        let control = match last_control {
+                            Some(('Q', control)) => {
+                                (2.0 * position.0 - control.0, 2.0 * position.1 - control.1)
+                            }
+                            _ => position,
+                        };
+
+                        (control, point(0))
+                    };
+
+                    flatten_quadratic(&mut path, position, control, to);
+                    last_control = Some(('Q', control));
+                    position = to;
+                }
+
+                continue;
+            }
+            'A' => {
+                for values in numbers.chunks(arity) {
+                    let origin = base(position);
+                    let to = (origin.0 + values[5], origin.1 + values[6]);
+
+                    flatten_arc(
+                        &mut path,
+                        position,
+                        (values[0], values[1]),
+                        values[2],
+                        values[3] != 0.0,
+                        values[4] != 0.0,
+                        to,
+                    );
+                    position = to;
+                }
+            }
+            'Z' => {
+                path.push(start);
+                position = start;
+            }
+            _ => {
+                return Err(SvgImportError::UnsupportedCommand(format!(
+                    "Unsupported path command {}",
+                    command
+                )))
+            }
+        }
+
+        last_control = None;
+    }
+
+    if path.len() > 1 {
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+fn shape_paths(tag: &Tag) -> Result<Vec<Vec<(f64, f64)>>, SvgImportError> {
+    let attribute = |name: &str| tag.attributes.get(name).map(String::as_str);
+    let number = |name: &str| -> Result<f64, SvgImportError> {
+        attribute(name)
+            .unwrap_or("0")
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| SvgImportError::InvalidAttribute(format!("Invalid {} attribute", name)))
+    };
+
+    Ok(match tag.name.as_str() {
+        "path" => parse_path_data(attribute("d").unwrap_or_default())?,
+        "polyline" | "polygon" => {
+            let numbers = parse_numbers(attribute("points").unwrap_or_default())?;
+            let mut points = numbers
+                .chunks_exact(2)
+                .map(|pair| (pair[0], pair[1]))
+                .collect::<Vec<_>>();
+
+            if tag.name == "polygon" && !points.is_empty() {
+                points.push(points[0]);
+            }
+
+            vec![points]
+        }
+        "line" => vec![vec![
+            (number("x1")?, number("y1")?),
+            (number("x2")?, number("y2")?),
+        ]],
+        _ => Vec::new(),
+    })
+}
+
+pub(crate) fn to_edges(paths: Vec<Vec<(f64, f64)>>, options: &SvgImportOptions) -> Vec<Vec<Edge>> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let mut edges = path
+                .into_iter()
+                .map(|(x, y)| Edge {
+                    x: ((x + options.offset.0) * options.scale).round() as i32,
+                    y: ((y + options.offset.1) * options.scale).round() as i32,
+                })
+                .collect::<Vec<_>>();
+
+            edges.dedup();
+            edges
+        })
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
+pub(crate) fn glyph_from_paths(paths: Vec<Vec<Edge>>) -> HersheyGlyph {
+    let (left, right) = paths
+        .iter()
+        .flatten()
+        .fold(None, |accum: Option<(i32, i32)>, edge| {
+            Some(match accum {
+                Some((left, right)) => (left.min(edge.x), right.max(edge.x)),
+                None => (edge.x, edge.x),
+            })
+        })
+        .unwrap_or((0, 0));
+
+    HersheyGlyph::new(left, right, paths)
+}
+
+pub fn glyph_from_svg(
+    svg: &str,
+    options: &SvgImportOptions,
+) -> Result<HersheyGlyph, SvgImportError> {
+    let mut paths = Vec::new();
+
+    for tag in parse_tags(svg).iter().filter(|tag| !tag.closing) {
+        paths.extend(shape_paths(tag)?);
+    }
+
+    Ok(glyph_from_paths(to_edges(paths, options)))
+}
+
+fn single_char(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+
+    chars.next().filter(|_| chars.next().is_none())
+}
+
+pub fn font_from_svg_groups(
+    svg: &str,
+    options: &SvgImportOptions,
+) -> Result<HersheyFont, SvgImportError> {
+    let mut glyphs: BTreeMap<char, Vec<Vec<(f64, f64)>>> = BTreeMap::new();
+    let mut groups: Vec<Option<char>> = Vec::new();
+
+    for tag in parse_tags(svg) {
+        if tag.name == "g" {
+            if tag.closing {
+                groups.pop();
+            } else if !tag.self_closing {
+                let character = ["data-char", "id"]
+                    .iter()
+                    .find_map(|key| tag.attributes.get(*key).and_then(|id| single_char(id)));
+
+                groups.push(character.or_else(|| groups.last().copied().flatten()));
+            }
+
+            continue;
+        }
+
+        if tag.closing {
+            continue;
+        }
+
+        if let Some(character) = groups.last().copied().flatten() {
+            glyphs
+                .entry(character)
+                .or_default()
+                .extend(shape_paths(&tag)?);
+        }
+    }
+
+    Ok(font_from_mapped_glyphs(
+        BTreeMap::new(),
+        glyphs
+            .into_iter()
+            .map(|(c, paths)| (c, glyph_from_paths(to_edges(paths, options))))
+            .collect(),
+    ))
+}
+
+pub fn font_from_svg_files<'a>(
+    files: impl IntoIterator<Item = (char, &'a str)>,
+    options: &SvgImportOptions,
+) -> Result<HersheyFont, SvgImportError> {
+    let glyphs = files
+        .into_iter()
+        .map(|(c, svg)| Ok((c, glyph_from_svg(svg, options)?)))
+        .collect::<Result<BTreeMap<_, _>, SvgImportError>>()?;
+
+    Ok(font_from_mapped_glyphs(BTreeMap::new(), glyphs))
+}
+
+pub fn font_from_svg_font(
+    svg: &str,
+    options: &SvgImportOptions,
+) -> Result<HersheyFont, SvgImportError> {
+    let mut metadata = BTreeMap::new();
+    let mut default_advance = 0.0;
+    let mut glyphs = BTreeMap::new();
+    let mut missing = None;
+
+    let advance = |tag: &Tag, default: f64| -> Result<f64, SvgImportError> {
+        match tag.attributes.get("horiz-adv-x") {
+            Some(value) => value.trim().parse::<f64>().map_err(|_| {
+                SvgImportError::InvalidAttribute("Invalid horiz-adv-x attribute".to_string())
+            }),
+            None => Ok(default),
+        }
+    };
+
+    for tag in parse_tags(svg).iter().filter(|tag| !tag.closing) {
+        match tag.name.as_str() {
+            "font" => {
+                default_advance = advance(tag, default_advance)?;
+
+                if let Some(id) = tag.attributes.get("id") {
+                    metadata.insert("name".to_string(), id.clone());
+                }
+            }
+            "font-face" => {
+                for (attribute, key) in
+                    [("font-family", "family"), ("units-per-em", "units-per-em")]
+                {
+                    if let Some(value) = tag.attributes.get(attribute) {
+                        metadata.insert(key.to_string(), value.clone());
+                    }
+                }
+            }
+            "glyph" | "missing-glyph" => {
+                let paths = parse_path_data(tag.attributes.get("d").map_or("", String::as_str))?
+                    .into_iter()
+                    .map(|path| path.into_iter().map(|(x, y)| (x, -y)).collect())
+                    .collect();
+                let mut glyph = glyph_from_paths(to_edges(paths, options));
+
+                glyph.left = (options.offset.0 * options.scale).round() as i32;
+                glyph.right = ((advance(tag, default_advance)? + options.offset.0) * options.scale)
+                    .round() as i32;
+
+                if tag.name == "missing-glyph" {
+                    missing = Some(glyph);
+                } else if let Some(c) = tag.attributes.get("unicode").and_then(|u| single_char(u)) {
+                    glyphs.insert(c, glyph);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let last_ascii = glyphs.range(..'\u{7f}').next_back().map(|(c, _)| *c);
+
+    if let (Some(missing), Some(last)) = (missing, last_ascii) {
+        for c in (' '..=last)
+            .filter(|c| !glyphs.contains_key(c))
+            .collect::<Vec<_>>()
+        {
+            glyphs.insert(c, missing.clone());
+        }
+    }
+
+    Ok(font_from_mapped_glyphs(metadata, glyphs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_path_data_works() {
+        let paths = parse_path_data("M0,0 L10 0 h5 v-5 m1 1 l1-1 z").unwrap();
+
+        assert_eq!(
+            paths,
+            vec![
+                vec![(0.0, 0.0), (10.0, 0.0), (15.0, 0.0), (15.0, -5.0)],
+                vec![(16.0, -4.0), (17.0, -5.0), (16.0, -4.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_path_data_flattens_curves() {
+        let paths = parse_path_data("M0 0 C0 10 10 10 10 0 S20 -10 20 0 Q25 5 30 0 T40 0").unwrap();
+        let path = &paths[0];
+
+        assert_eq!(path.len(), 1 + 4 * CURVE_SEGMENTS);
+        assert_eq!(path[CURVE_SEGMENTS / 2], (5.0, 7.5));
+        assert_eq!(path[CURVE_SEGMENTS * 3 / 2], (15.0, -7.5));
+        assert_eq!(path[CURVE_SEGMENTS * 5 / 2], (25.0, 2.5));
+        assert_eq!(path[CURVE_SEGMENTS * 7 / 2], (35.0, -2.5));
+        assert_eq!(path.last(), Some(&(40.0, 0.0)));
+    }
+
+    #[test]
+    fn parse_path_data_flattens_arcs() {
+        let paths = parse_path_data("M0 0 A5 5 0 0 1 10 0").unwrap();
+        let (x, y) = paths[0][CURVE_SEGMENTS / 2];
+
+        assert!((x - 5.0).abs() < 1e-9 && (y + 5.0).abs() < 1e-9);
+        assert_eq!(paths[0].last(), Some(&(10.0, 0.0)));
+    }
+
+    #[test]
+    fn parse_path_data_returns_error_for_unsupported_commands() {
+        assert!(parse_path_data("M0 0 X1 1").is_err());
+    }
+
+    #[test]
+    fn glyph_from_svg_works() {
+        let svg = r#"<svg><polyline points="0,0 4,8" /><line x1="4" y1="0" x2="4" y2="8"/></svg>"#;
+        let glyph = glyph_from_svg(svg, &SvgImportOptions::default()).unwrap();
+
+        assert_eq!(
+            glyph,
+            HersheyGlyph::new(
+                0,
+                4,
+                vec![
+                    vec![Edge { x: 0, y: 0 }, Edge { x: 4, y: 8 }],
+                    vec![Edge { x: 4, y: 0 }, Edge { x: 4, y: 8 }],
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn font_from_svg_groups_works() {
+        let svg = r#"<svg>
+            <g id="A"><path d="M0 0 L2 2"/></g>
+            <g id="&#x42;"><g><polyline points="1 1 3 3"/></g></g>
+        </svg>"#;
+        let font = font_from_svg_groups(svg, &SvgImportOptions::default()).unwrap();
+
+        assert_eq!(font.get_glyph('A').unwrap().paths.len(), 1);
+        assert_eq!(font.get_glyph('B').unwrap().left, 1);
+        assert!(font.get_glyph('!').is_err());
+        assert!(font.get_glyph('C').is_err());
+    }
+
+    #[test]
+    fn font_from_svg_files_works() {
+        let font = font_from_svg_files(
+            [(' ', "<svg/>"), ('!', r#"<svg><path d="M0 0 V10"/></svg>"#)],
+            &SvgImportOptions {
+                scale: 2.0,
+                ..SvgImportOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(font.get_glyph('!').unwrap().bottom, 20);
+    }
+
+    #[test]
+    fn font_from_svg_files_maps_sparse_chars() {
+        let font = font_from_svg_files(
+            [
+                ('\u{1}', r#"<svg><path d="M0 0 V4"/></svg>"#),
+                ('🙂', r#"<svg><path d="M0 0 V8"/></svg>"#),
+            ],
+            &SvgImportOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(font.len(), 2);
+        assert_eq!(font.get_glyph('\u{1}').unwrap().bottom, 4);
+        assert_eq!(font.get_glyph('🙂').unwrap().bottom, 8);
+    }
+
+    #[test]
+    fn font_from_svg_font_works() {
+        let svg = r#"<svg><defs><font id="hershey-sans" horiz-adv-x="10">
+            <font-face font-family="Hershey Sans" units-per-em="1000"/>
+            <missing-glyph d="M0 0 L5 5"/>
+            <glyph unicode=" " horiz-adv-x="8"/>
+            <glyph unicode="&quot;" d="M2 10 V6 M4 10 V6"/>
+        </font></defs></svg>"#;
+        let font = font_from_svg_font(svg, &SvgImportOptions::default()).unwrap();
+
+        assert_eq!(font.meta().unwrap().name.as_deref(), Some("hershey-sans"));
+        assert_eq!(font.meta().unwrap().family.as_deref(), Some("Hershey Sans"));
+        assert_eq!(font.get_glyph(' ').unwrap().right, 8);
+        assert_eq!(
+            font.get_glyph('!').unwrap().paths,
+            vec![vec![Edge { x: 0, y: 0 }, Edge { x: 5, y: -5 }]]
+        );
+
+        let quote = font.get_glyph('"').unwrap();
+
+        assert_eq!((quote.left, quote.right), (0, 10));
+        assert_eq!(
+            quote.paths[0],
+            vec![Edge { x: 2, y: -10 }, Edge { x: 2, y: -6 }]
+        );
+    }
+
+    #[test]
+    fn font_from_svg_font_maps_non_ascii_glyphs() {
+        let svg = r#"<font horiz-adv-x="10">
+            <glyph unicode="!" d="M0 0 L0 5"/>
+            <glyph unicode="&#x3a9;" horiz-adv-x="12" d="M0 0 L6 0"/>
+            <glyph unicode="é" d="M1 1 L2 2"/>
+        </font>"#;
+        let font = font_from_svg_font(svg, &SvgImportOptions::default()).unwrap();
+
+        assert_eq!(font.len(), 3);
+        assert_eq!(font.get_glyph('Ω').unwrap().right, 12);
+        assert_eq!(
+            font.get_glyph('é').unwrap().paths,
+            vec![vec![Edge { x: 1, y: -1 }, Edge { x: 2, y: -2 }]]
+        );
+        assert_eq!(font.get_glyph('!').unwrap().paths[0].len(), 2);
+        assert!(font.get_glyph('"').is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use crate::glyph_table::unicode_to_number;
+
+const EXTRA_SYMBOLS: &[(char, u32)] = &[('µ', 638), ('ς', 644), ('ϑ', 634), ('ϕ', 647), ('°', 718)];
+
+fn is_symbol_number(number: &u32) -> bool {
+    (527..=550).contains(number) || (627..=650).contains(number) || *number >= 2000
+}
+
+pub fn symbol_number(c: char) -> Option<u32> {
+    EXTRA_SYMBOLS
+        .iter()
+        .find(|(symbol, _)| *symbol == c)
+        .map(|(_, number)| *number)
+        .or_else(|| unicode_to_number(c).filter(is_symbol_number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbol_number_works() {
+        assert_eq!(symbol_number('Α'), Some(527));
+        assert_eq!(symbol_number('Ω'), Some(550));
+        assert_eq!(symbol_number('π'), Some(642));
+        assert_eq!(symbol_number('µ'), Some(638));
+        assert_eq!(symbol_number('<'), Some(2241));
+        assert_eq!(symbol_number('±'), Some(2233));
+        assert_eq!(symbol_number('×'), Some(2235));
+        assert_eq!(symbol_number('÷'), Some(2237));
+        assert_eq!(symbol_number('∞'), Some(2270));
+        assert_eq!(symbol_number('°'), Some(718));
+        assert_eq!(symbol_number('A'), None);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::layout::{LayoutOptions, PositionedGlyph};
+use crate::transform::{transform_paths, Transform2D};
+use crate::{Edge, HersheyFont, PointF};
+
+fn scale_paths(paths: &[Vec<Edge>], origin: PointF, scale: f64) -> Vec<Vec<PointF>> {
+    transform_paths(
+        paths,
+        &Transform2D::scale(scale, scale).then(&Transform2D::translate(origin.x, origin.y)),
+    )
+}
+
+pub(crate) fn place_text(
+    font: &HersheyFont,
+    text: &str,
+    origin: PointF,
+    scale: f64,
+) -> (Vec<Vec<PointF>>, f64) {
+    let layout = font.layout_text(text);
+
+    (
+        scale_paths(&layout.paths(), origin, scale),
+        layout.width as f64 * scale,
+    )
+}
+
+pub(crate) fn layout_to_paths(
+    font: &HersheyFont,
+    glyphs: &[PositionedGlyph],
+    size: f64,
+) -> Vec<Vec<PointF>> {
+    let paths = glyphs
+        .iter()
+        .flat_map(|glyph| glyph.paths.iter().cloned())
+        .collect::<Vec<_>>();
+
+    scale_paths(&paths, PointF { x: 0.0, y: 0.0 }, size / font_height(font))
+}
+
+pub(crate) fn font_height(font: &HersheyFont) -> f64 {
+    if font.bottom > font.top {
+        (font.bottom - font.top) as f64
+    } else {
+        1.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Anchor {
+    #[default]
+    BaselineStart,
+    TopLeft,
+    Center,
+    CapHeightCenter,
+}
+
+pub fn text_to_paths_anchored(
+    font: &HersheyFont,
+    text: &str,
+    size: f64,
+    anchor: Anchor,
+) -> Vec<Vec<PointF>> {
+    text_to_paths_with_options(
+        font,
+        text,
+        size,
+        &LayoutOptions {
+            anchor: Some(anchor),
+            ..LayoutOptions::default()
+        },
+    )
+}
+
+pub fn text_to_paths(font: &HersheyFont, text: &str, size: f64) -> Vec<Vec<PointF>> {
+    text_to_paths_with_options(font, text, size, &LayoutOptions::default())
+}
+
+pub fn text_to_paths_with_options(
+    font: &HersheyFont,
+    text: &str,
+    size: f64,
+    options: &LayoutOptions,
+) -> Vec<Vec<PointF>> {
+    let layout = font.layout_text_with_options(text, options);
+
+    layout_to_paths(font, &layout.glyphs, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::HersheyFontBuilder;
+    use crate::layout::Alignment;
+    use crate::HersheyGlyph;
+
+    #[test]
+    fn place_text_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\").unwrap();
+        let (paths, width) = place_text(&font, " ! ", PointF { x: 1.0, y: 2.0 }, 0.5);
+
+        assert_eq!(width, 24.0);
+        assert_eq!(
+            paths,
+            vec![vec![
+                PointF { x: 13.0, y: -3.0 },
+                PointF { x: 13.0, y: 7.0 }
+            ]]
+        );
+    }
+
+    #[test]
+    fn text_to_paths_anchored_works() {
+        let font = HersheyFontBuilder::new()
+            .glyph(
+                'H',
+                HersheyGlyph::new(
+                    -8,
+                    8,
+                    vec![vec![Edge { x: 0, y: -10 }, Edge { x: 0, y: 10 }]],
+                ),
+            )
+            .build()
+            .unwrap();
+
+        let baseline = text_to_paths_anchored(&font, "H", 20.0, Anchor::BaselineStart);
+        let top_left = text_to_paths_anchored(&font, "H", 20.0, Anchor::TopLeft);
+        let center = text_to_paths_anchored(&font, "H", 20.0, Anchor::Center);
+        let cap_center = text_to_paths_anchored(&font, "H", 20.0, Anchor::CapHeightCenter);
+
+        assert_eq!(baseline[0][1], PointF { x: 8.0, y: 0.0 });
+        assert_eq!(top_left[0][0], PointF { x: 0.0, y: 0.0 });
+        assert_eq!(center[0][0], PointF { x: 0.0, y: -10.0 });
+        assert_eq!(cap_center[0][0], PointF { x: 0.0, y: -10.0 });
+    }
+
+    #[test]
+    fn text_to_paths_works() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\").unwrap();
+        let paths = text_to_paths(&font, "!\n !", 10.0);
+
+        assert_eq!(
+            paths,
+            vec![
+                vec![PointF { x: 4.0, y: -5.0 }, PointF { x: 4.0, y: 5.0 }],
+                vec![PointF { x: 12.0, y: 5.0 }, PointF { x: 12.0, y: 15.0 }],
+            ]
+        );
+    }
+
+    #[test]
+    fn text_to_paths_with_options_uses_layout() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\").unwrap();
+        let paths = text_to_paths_with_options(
+            &font,
+            "! !",
+            10.0,
+            &LayoutOptions {
+                max_width: Some(20),
+                tracking: 2,
+                alignment: Alignment::Right,
+                ..LayoutOptions::default()
+            },
+        );
+
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0][0], PointF { x: 6.0, y: -5.0 });
+        assert_eq!(paths[1][0], PointF { x: 6.0, y: 5.0 });
+    }
+}
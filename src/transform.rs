@@ -0,0 +1,179 @@
+use crate::layout::TextLayout;
+use crate::{Edge, HersheyGlyph, PointF};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform2D {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Default for Transform2D {
+    fn default() -> Transform2D {
+        Transform2D::identity()
+    }
+}
+
+impl Transform2D {
+    pub fn identity() -> Transform2D {
+        Transform2D {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    pub fn translate(x: f64, y: f64) -> Transform2D {
+        Transform2D {
+            e: x,
+            f: y,
+            ..Transform2D::identity()
+        }
+    }
+
+    pub fn scale(x: f64, y: f64) -> Transform2D {
+        Transform2D {
+            a: x,
+            d: y,
+            ..Transform2D::identity()
+        }
+    }
+
+    pub fn rotate(degrees: f64) -> Transform2D {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        Transform2D {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            ..Transform2D::identity()
+        }
+    }
+
+    pub fn skew_x(degrees: f64) -> Transform2D {
+        Transform2D {
+            c: degrees.to_radians().tan(),
+            ..Transform2D::identity()
+        }
+    }
+
+    pub fn mirror_x() -> Transform2D {
+        Transform2D::scale(-1.0, 1.0)
+    }
+
+    pub fn flip_y() -> Transform2D {
+        Transform2D::scale(1.0, -1.0)
+    }
+
+    pub fn then(&self, next: &Transform2D) -> Transform2D {
+        Transform2D {
+            a: next.a * self.a + next.c * self.b,
+            b: next.b * self.a + next.d * self.b,
+            c: next.a * self.c + next.c * self.d,
+            d: next.b * self.c + next.d * self.d,
+            e: next.a * self.e + next.c * self.f + next.e,
+            f: next.b * self.e + next.d * self.f + next.f,
+        }
+    }
+
+    pub fn apply(&self, point: PointF) -> PointF {
+        PointF {
+            x: self.a * point.x + self.c * point.y + self.e,
+            y: self.b * point.x + self.d * point.y + self.f,
+        }
+    }
+
+    pub fn apply_edge(&self, edge: &Edge) -> PointF {
+        self.apply(PointF::from(*edge))
+    }
+}
+
+pub fn transform_paths(paths: &[Vec<Edge>], transform: &Transform2D) -> Vec<Vec<PointF>> {
+    paths
+        .iter()
+        .map(|path| path.iter().map(|edge| transform.apply_edge(edge)).collect())
+        .collect()
+}
+
+impl HersheyGlyph {
+    pub fn transformed_iter<'a>(
+        &'a self,
+        transform: &'a Transform2D,
+    ) -> impl Iterator<Item = impl Iterator<Item = PointF> + 'a> + 'a {
+        self.paths
+            .iter()
+            .map(move |path| path.iter().map(move |edge| transform.apply_edge(edge)))
+    }
+
+    pub fn transformed(&self, transform: &Transform2D) -> Vec<Vec<PointF>> {
+        transform_paths(&self.paths, transform)
+    }
+}
+
+impl TextLayout {
+    pub fn transformed(&self, transform: &Transform2D) -> Vec<Vec<PointF>> {
+        transform_paths(&self.paths(), transform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(point: PointF, x: f64, y: f64) {
+        assert!(
+            (point.x - x).abs() < 1e-9 && (point.y - y).abs() < 1e-9,
+            "{:?}",
+            point
+        );
+    }
+
+    #[test]
+    fn then_applies_in_order() {
+        let transform = Transform2D::scale(2.0, 2.0)
+            .then(&Transform2D::rotate(90.0))
+            .then(&Transform2D::translate(1.0, 0.0));
+
+        assert_close(transform.apply(PointF { x: 1.0, y: 0.0 }), 1.0, 2.0);
+        assert_close(
+            Transform2D::flip_y().apply(PointF { x: 3.0, y: 4.0 }),
+            3.0,
+            -4.0,
+        );
+        assert_close(
+            Transform2D::skew_x(45.0).apply(PointF { x: 0.0, y: 2.0 }),
+            2.0,
+            2.0,
+        );
+    }
+
+    #[test]
+    fn transformed_works() {
+        let glyph = HersheyGlyph::new(
+            -5,
+            5,
+            vec![vec![Edge { x: 0, y: -10 }, Edge { x: 2, y: 10 }]],
+        );
+        let transform = Transform2D::mirror_x().then(&Transform2D::translate(0.0, 10.0));
+        let paths = glyph.transformed(&transform);
+
+        assert_eq!(
+            paths,
+            vec![vec![PointF { x: 0.0, y: 0.0 }, PointF { x: -2.0, y: 20.0 }]]
+        );
+        assert_eq!(
+            glyph
+                .transformed_iter(&transform)
+                .map(Iterator::collect::<Vec<_>>)
+                .collect::<Vec<_>>(),
+            paths
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::HersheyFont;
+
+const MM_PER_INCH: f64 = 25.4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Units {
+    Millimeters,
+    Inches,
+    Pixels { dpi: f64 },
+}
+
+impl Units {
+    pub fn per_mm(&self) -> f64 {
+        match self {
+            Units::Millimeters => 1.0,
+            Units::Inches => 1.0 / MM_PER_INCH,
+            Units::Pixels { dpi } => dpi / MM_PER_INCH,
+        }
+    }
+
+    pub fn factor(&self, to: Units) -> f64 {
+        to.per_mm() / self.per_mm()
+    }
+
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Units::Millimeters => "mm",
+            Units::Inches => "in",
+            Units::Pixels { .. } => "px",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Length {
+    pub value: f64,
+    pub units: Units,
+}
+
+impl Length {
+    pub fn mm(value: f64) -> Length {
+        Length {
+            value,
+            units: Units::Millimeters,
+        }
+    }
+
+    pub fn inches(value: f64) -> Length {
+        Length {
+            value,
+            units: Units::Inches,
+        }
+    }
+
+    pub fn px(value: f64, dpi: f64) -> Length {
+        Length {
+            value,
+            units: Units::Pixels { dpi },
+        }
+    }
+
+    pub fn to_mm(&self) -> f64 {
+        self.value / self.units.per_mm()
+    }
+
+    pub fn to(&self, units: Units) -> f64 {
+        self.to_mm() * units.per_mm()
+    }
+}
+
+impl HersheyFont {
+    pub fn scale_for_cap_height(&self, cap_height: Length, units: Units) -> Option<f64> {
+        Some(cap_height.to(units) / self.measured_cap_height()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::HersheyFontBuilder;
+    use crate::{Edge, HersheyGlyph};
+
+    #[test]
+    fn length_conversion_works() {
+        assert!((Length::inches(1.0).to_mm() - 25.4).abs() < 1e-9);
+        assert!((Length::mm(25.4).to(Units::Pixels { dpi: 96.0 }) - 96.0).abs() < 1e-9);
+        assert!((Length::px(48.0, 96.0).to(Units::Inches) - 0.5).abs() < 1e-9);
+        assert!((Units::Inches.factor(Units::Millimeters) - 25.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn scale_for_cap_height_works() {
+        let font = HersheyFontBuilder::new()
+            .glyph(
+                'H',
+                HersheyGlyph::new(
+                    -8,
+                    8,
+                    vec![vec![Edge { x: 0, y: -10 }, Edge { x: 0, y: 10 }]],
+                ),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            font.scale_for_cap_height(Length::mm(12.0), Units::Millimeters),
+            Some(0.6)
+        );
+    }
+}
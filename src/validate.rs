@@ -0,0 +1,179 @@
+use crate::{HersheyFont, HersheyGlyph};
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GlyphIssue {
+    InvertedBearings { left: i32, right: i32 },
+    EmptyPath { path: usize },
+    ZeroLengthPath { path: usize },
+    DuplicatePoint { path: usize, index: usize },
+    OutsideBearings { path: usize, index: usize, x: i32 },
+}
+
+impl fmt::Display for GlyphIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GlyphIssue::InvertedBearings { left, right } => {
+                write!(
+                    f,
+                    "left bearing {} is right of right bearing {}",
+                    left, right
+                )
+            }
+            GlyphIssue::EmptyPath { path } => write!(f, "path {} is empty", path),
+            GlyphIssue::ZeroLengthPath { path } => write!(f, "path {} has zero length", path),
+            GlyphIssue::DuplicatePoint { path, index } => {
+                write!(f, "path {} repeats vertex {}", path, index)
+            }
+            GlyphIssue::OutsideBearings { path, index, x } => write!(
+                f,
+                "vertex {} of path {} at x = {} is outside the bearings",
+                index, path, x
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphDiagnostic {
+    pub index: usize,
+    pub number: u32,
+    pub chars: Vec<char>,
+    pub issue: GlyphIssue,
+}
+
+impl fmt::Display for GlyphDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Glyph {} (number {}", self.index, self.number)?;
+
+        if !self.chars.is_empty() {
+            write!(f, ", {:?}", self.chars.iter().collect::<String>())?;
+        }
+
+        write!(f, "): {}", self.issue)
+    }
+}
+
+impl HersheyGlyph {
+    pub fn validate(&self) -> Vec<GlyphIssue> {
+        let mut issues = Vec::new();
+
+        if self.left > self.right {
+            issues.push(GlyphIssue::InvertedBearings {
+                left: self.left,
+                right: self.right,
+            });
+        }
+
+        for (i, path) in self.paths.iter().enumerate() {
+            if path.is_empty() {
+                issues.push(GlyphIssue::EmptyPath { path: i });
+                continue;
+            }
+
+            if path.len() > 1 && path.iter().all(|edge| *edge == path[0]) {
+                issues.push(GlyphIssue::ZeroLengthPath { path: i });
+            } else {
+                issues.extend(
+                    path.windows(2)
+                        .enumerate()
+                        .filter(|(_, pair)| pair[0] == pair[1])
+                        .map(|(index, _)| GlyphIssue::DuplicatePoint {
+                            path: i,
+                            index: index + 1,
+                        }),
+                );
+            }
+
+            issues.extend(
+                path.iter()
+                    .enumerate()
+                    .filter(|(_, edge)| edge.x < self.left || edge.x > self.right)
+                    .map(|(index, edge)| GlyphIssue::OutsideBearings {
+                        path: i,
+                        index,
+                        x: edge.x,
+                    }),
+            );
+        }
+
+        issues
+    }
+}
+
+impl HersheyFont {
+    pub fn diagnostics(&self) -> Vec<GlyphDiagnostic> {
+        self.glyphs
+            .iter()
+            .enumerate()
+            .flat_map(|(index, glyph)| {
+                glyph
+                    .validate()
+                    .into_iter()
+                    .map(move |issue| GlyphDiagnostic {
+                        index,
+                        number: glyph.number,
+                        chars: self.chars_for_glyph(index),
+                        issue,
+                    })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Edge;
+
+    #[test]
+    fn validate_works() {
+        let glyph = HersheyGlyph::new(
+            -5,
+            5,
+            vec![
+                vec![Edge { x: 0, y: 0 }, Edge { x: 0, y: 0 }],
+                vec![],
+                vec![
+                    Edge { x: 0, y: 0 },
+                    Edge { x: 6, y: 0 },
+                    Edge { x: 6, y: 0 },
+                ],
+            ],
+        );
+
+        assert_eq!(
+            glyph.validate(),
+            vec![
+                GlyphIssue::ZeroLengthPath { path: 0 },
+                GlyphIssue::EmptyPath { path: 1 },
+                GlyphIssue::DuplicatePoint { path: 2, index: 2 },
+                GlyphIssue::OutsideBearings {
+                    path: 2,
+                    index: 1,
+                    x: 6
+                },
+                GlyphIssue::OutsideBearings {
+                    path: 2,
+                    index: 2,
+                    x: 6
+                },
+            ]
+        );
+        assert!(HersheyGlyph::new(2, -2, Vec::new())
+            .validate()
+            .contains(&GlyphIssue::InvertedBearings { left: 2, right: -2 }));
+    }
+
+    #[test]
+    fn diagnostics_works() {
+        let font = HersheyFont::new("  699  1JZ\n  714  3MWRHY\\").unwrap();
+        let diagnostics = font.diagnostics();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "Glyph 1 (number 714, \"!\"): vertex 1 of path 0 at x = 7 is outside the bearings"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::text::{text_to_paths_anchored, Anchor};
+use crate::{HersheyFont, PointF};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WatermarkOptions {
+    pub page_width: f64,
+    pub page_height: f64,
+    pub size: f64,
+    pub angle_degrees: f64,
+    pub spacing: (f64, f64),
+    pub offset: PointF,
+    pub stagger: f64,
+}
+
+impl Default for WatermarkOptions {
+    fn default() -> WatermarkOptions {
+        WatermarkOptions {
+            page_width: 210.0,
+            page_height: 297.0,
+            size: 10.0,
+            angle_degrees: 30.0,
+            spacing: (20.0, 20.0),
+            offset: PointF { x: 0.0, y: 0.0 },
+            stagger: 0.5,
+        }
+    }
+}
+
+fn clip_segment(a: PointF, b: PointF, width: f64, height: f64) -> Option<(PointF, PointF)> {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+
+    for (p, q) in [
+        (-dx, a.x),
+        (dx, width - a.x),
+        (-dy, a.y),
+        (dy, height - a.y),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+
+    (t0 <= t1).then_some({
+        (
+            PointF {
+                x: a.x + t0 * dx,
+                y: a.y + t0 * dy,
+            },
+            PointF {
+                x: a.x + t1 * dx,
+                y: a.y + t1 * dy,
+            },
+        )
+    })
+}
+
+pub(crate) fn clip_paths(paths: &[Vec<PointF>], width: f64, height: f64) -> Vec<Vec<PointF>> {
+    let mut clipped: Vec<Vec<PointF>> = Vec::new();
+
+    for path in paths {
+        let mut current: Vec<PointF> = Vec::new();
+
+        for pair in path.windows(2) {
+            match clip_segment(pair[0], pair[1], width, height) {
+                Some((a, b)) => {
+                    if current.last() != Some(&a) {
+                        if current.len() > 1 {
+                            clipped.push(std::mem::take(&mut current));
+                        }
+
+                        current = vec![a];
+                    }
+
+                    current.push(b);
+                }
+                None => {
+                    if current.len() > 1 {
+                        clipped.push(std::mem::take(&mut current));
+                    }
+
+                    current.clear();
+                }
+            }
+        }
+
+        if current.len() > 1 {
+            clipped.push(current);
+        }
+    }
+
+    clipped
+}
+
+pub fn watermark(font: &HersheyFont, text: &str, options: &WatermarkOptions) -> Vec<Vec<PointF>> {
+    let tile = text_to_paths_anchored(font, text, options.size, Anchor::Center);
+
+    let (min_x, max_x, min_y, max_y) = tile.iter().flatten().fold(
+        (0.0f64, 0.0f64, 0.0f64, 0.0f64),
+        |(min_x, max_x, min_y, max_y), point| {
+            (
+                min_x.min(point.x),
+                max_x.max(point.x),
+                min_y.min(point.y),
+                max_y.max(point.y),
+            )
+        },
+    );
+
+    let step_x = (max_x - min_x + options.spacing.0).max(1.0);
+    let step_y = (max_y - min_y + options.spacing.1).max(1.0);
+    let (sin, cos) = options.angle_degrees.to_radians().sin_cos();
+    let center = PointF {
+        x: options.page_width / 2.0 + options.offset.x,
+        y: options.page_height / 2.0 + options.offset.y,
+    };
+    let reach = options.page_width.hypot(options.page_height) / 2.0
+        + options.offset.x.hypot(options.offset.y);
+    let columns = (reach / step_x).ceil() as i64 + 1;
+    let rows = (reach / step_y).ceil() as i64 + 1;
+
+    let mut paths = Vec::new();
+
+    for row in -rows..=rows {
+        let shift = if row % 2 == 0 {
+            0.0
+        } else {
+            options.stagger * step_x
+        };
+
+        for column in -columns..=columns {
+            let tile_x = column as f64 * step_x + shift;
+            let tile_y = row as f64 * step_y;
+
+            let placed = tile
+                .iter()
+                .map(|path| {
+                    path.iter()
+                        .map(|point| {
+                            let (x, y) = (point.x + tile_x, point.y + tile_y);
+
+                            PointF {
+                                x: center.x + x * cos - y * sin,
+                                y: center.y + x * sin + y * cos,
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            paths.extend(clip_paths(&placed, options.page_width, options.page_height));
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clip_paths_works() {
+        let paths = vec![vec![
+            PointF { x: -5.0, y: 5.0 },
+            PointF { x: 5.0, y: 5.0 },
+            PointF { x: 5.0, y: 15.0 },
+        ]];
+
+        assert_eq!(
+            clip_paths(&paths, 10.0, 10.0),
+            vec![vec![
+                PointF { x: 0.0, y: 5.0 },
+                PointF { x: 5.0, y: 5.0 },
+                PointF { x: 5.0, y: 10.0 },
+            ]]
+        );
+    }
+
+    #[test]
+    fn watermark_stays_within_page() {
+        let font = HersheyFont::new("  720  1JZ\n  720  3JZRHR\\").unwrap();
+        let options = WatermarkOptions {
+            page_width: 100.0,
+            page_height: 50.0,
+            ..WatermarkOptions::default()
+        };
+        let paths = watermark(&font, "!", &options);
+
+        assert!(paths.len() > 4);
+        assert!(paths.iter().flatten().all(|point| {
+            (-1e-9..=100.0 + 1e-9).contains(&point.x) && (-1e-9..=50.0 + 1e-9).contains(&point.y)
+        }));
+    }
+}